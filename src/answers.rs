@@ -0,0 +1,432 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::aoc_cli::SubmitOutcome;
+use crate::input::year_dir;
+use crate::results::{OutputFormat, PartResult};
+use crate::{ANSI_ITALIC, ANSI_RESET};
+
+/// Set once any part produces an answer which doesn't match the recorded one.
+static REGRESSION: AtomicBool = AtomicBool::new(false);
+
+/// The accepted answers for a day, as recorded in `src/answers/NN.toml`:
+/// ```toml
+/// part_one = 24000
+/// part_two = "45000"
+/// ```
+/// Answers which span several lines can use TOML's multi-line literal strings (`'''`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Returns the path of a file in the year's `answers` folder, e.g. `src/answers/01.toml`.
+fn answers_path(year: i16, day: u8, extension: &str) -> PathBuf {
+    year_dir(year)
+        .join("answers")
+        .join(format!("{:02}.{}", day, extension))
+}
+
+/// Creates the folder of a file in `answers_path`, which doesn't exist yet for a new year.
+fn create_answers_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(folder) => fs::create_dir_all(folder),
+        None => Ok(()),
+    }
+}
+
+impl Answers {
+    /// Loads the recorded answers for the given day.  Returns `None` if there are none.  A file
+    /// which can't be parsed is reported and otherwise treated as missing.
+    pub fn load(year: i16, day: u8) -> Option<Self> {
+        let path = answers_path(year, day, "toml");
+        let contents = fs::read_to_string(&path).ok()?;
+
+        match contents.parse() {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("Ignoring answers in \"{}\": {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Writes the answers to `src/answers/NN.toml` for the given day, replacing the file.
+    pub fn save(&self, year: i16, day: u8) -> io::Result<PathBuf> {
+        let path = answers_path(year, day, "toml");
+        create_answers_dir(&path)?;
+        fs::write(&path, self.to_toml())?;
+        Ok(path)
+    }
+
+    /// Returns the recorded answer for the given part, if any.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Records the answer for the given part.
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+
+    /// Formats the answers in the format read by `from_str`.
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                toml.push_str(&format!("{} = {}\n", key, toml_string(answer)));
+            }
+        }
+
+        toml
+    }
+}
+
+/// Quotes the given string as a TOML value; multi-line values become literal strings.
+fn toml_string(s: &str) -> String {
+    if s.contains('\n') && !s.contains("\'\'\'") {
+        return format!("\'\'\'\n{}\'\'\'", s);
+    }
+
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+
+    out
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    /// Parses the small subset of TOML used by answer files: `part_one` and `part_two` keys whose
+    /// values are integers, basic strings ("...") or literal strings ('...' and '''...''').
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut rest = s;
+
+        while !rest.is_empty() {
+            let line_start = s.len() - rest.len();
+            let line_num = s[..line_start].matches('\n').count() + 1;
+            let (line, after) = rest.split_once('\n').unwrap_or((rest, ""));
+            rest = after;
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = trimmed
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", line_num))?;
+            let value = value.trim_start();
+
+            let answer = if value.starts_with("'''") {
+                // Multi-line literal string, which continues past this line.  A newline directly
+                // after the opening quotes is not part of the value.
+                let open = line.find("'''").unwrap_or(0) + 3;
+                let body = &s[line_start + open..];
+                let body = body.strip_prefix('\n').unwrap_or(body);
+                let end = body
+                    .find("'''")
+                    .ok_or_else(|| format!("line {}: unterminated '''", line_num))?;
+
+                rest = body[end + 3..].split_once('\n').map_or("", |(_, r)| r);
+                body[..end].to_string()
+            } else if let Some(value) = value.strip_prefix('\'') {
+                let end = value
+                    .find('\'')
+                    .ok_or_else(|| format!("line {}: unterminated '", line_num))?;
+                value[..end].to_string()
+            } else if let Some(value) = value.strip_prefix('"') {
+                parse_basic_string(value).ok_or_else(|| format!("line {}: bad string", line_num))?
+            } else {
+                let value = value.split('#').next().unwrap_or("").trim();
+                value
+                    .parse::<i64>()
+                    .map_err(|_| format!("line {}: expected a string or integer", line_num))?
+                    .to_string()
+            };
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(answer),
+                "part_two" => answers.part_two = Some(answer),
+                k => return Err(format!("line {}: unknown key `{}`", line_num, k)),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Parses the contents of a basic string after its opening quote, handling escapes.
+fn parse_basic_string(s: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                'r' => out.push('\r'),
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+/// An answer which was submitted to the Advent of Code website, as recorded in
+/// `src/answers/NN.submissions`.  Each line of that file is a tab-separated part, outcome and
+/// answer (with newlines escaped as `\n`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub outcome: SubmitOutcome,
+    pub answer: String,
+}
+
+impl Submission {
+    /// Loads every recorded submission for the given day.  Lines which can't be read are skipped.
+    pub fn load_all(year: i16, day: u8) -> Vec<Submission> {
+        let contents =
+            fs::read_to_string(answers_path(year, day, "submissions")).unwrap_or_default();
+
+        contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                Some(Submission {
+                    part: fields.next()?.parse().ok()?,
+                    outcome: SubmitOutcome::from_name(fields.next()?)?,
+                    answer: fields.next()?.replace("\\n", "\n"),
+                })
+            })
+            .collect()
+    }
+
+    /// Appends this submission to the log for the given day.
+    pub fn record(&self, year: i16, day: u8) -> io::Result<()> {
+        let path = answers_path(year, day, "submissions");
+        create_answers_dir(&path)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        writeln!(
+            file,
+            "{}\t{}\t{}",
+            self.part,
+            self.outcome.name(),
+            self.answer.replace('\n', "\\n")
+        )
+    }
+
+    /// Finds an earlier judged submission which already tells us the outcome of submitting
+    /// `answer` for `part`: the same answer, or a number beyond one that was too high or too low.
+    pub fn find_conclusive<'a>(
+        submissions: &'a [Submission],
+        part: u8,
+        answer: &str,
+    ) -> Option<&'a Submission> {
+        let number = answer.parse::<i64>().ok();
+
+        submissions
+            .iter()
+            .filter(|s| s.part == part && s.outcome.is_judged())
+            .find(|s| {
+                if s.answer == answer {
+                    return true;
+                }
+
+                match (number, s.answer.parse::<i64>()) {
+                    (Some(n), Ok(previous)) => match s.outcome {
+                        SubmitOutcome::TooHigh => n >= previous,
+                        SubmitOutcome::TooLow => n <= previous,
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            })
+    }
+}
+
+/// How a part's answer compares to the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches.
+    Correct,
+    /// The answer differs from the recorded one (or the part is no longer solved).
+    Wrong { expected: String },
+    /// No answer is recorded for the part.
+    Unknown,
+}
+
+impl Verdict {
+    /// Compares a result with the recorded answers for its day.
+    pub fn check(result: &PartResult, answers: Option<&Answers>) -> Self {
+        match answers.and_then(|a| a.get(result.part)) {
+            None => Verdict::Unknown,
+            Some(expected) if result.answer.as_deref() == Some(expected) => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Checks a result against the recorded answers for its day and reports the outcome: in human
+/// output, ✅ or ❌ is printed after the result.  Wrong answers are also noted for
+/// `exit_on_regression`.
+pub fn verify(result: &PartResult, format: OutputFormat) -> Verdict {
+    let verdict = Verdict::check(result, Answers::load(result.year, result.day).as_ref());
+
+    match &verdict {
+        Verdict::Correct if format == OutputFormat::Human => println!("✅"),
+        Verdict::Wrong { expected } => {
+            REGRESSION.store(true, Ordering::Relaxed);
+
+            let message = format!(
+                "❌ {}day {} part {}: expected {}{}",
+                ANSI_ITALIC, result.day, result.part, expected, ANSI_RESET
+            );
+            match format {
+                OutputFormat::Human => println!("{}", message),
+                // Keep stdout machine-readable.
+                OutputFormat::Json => eprintln!("{}", message),
+            }
+        }
+        _ => {}
+    }
+
+    verdict
+}
+
+/// Whether any answer checked so far was wrong.
+pub fn has_regression() -> bool {
+    REGRESSION.load(Ordering::Relaxed)
+}
+
+/// Exits the process with a non-zero status if any answer checked so far was wrong.
+pub fn exit_on_regression() {
+    if has_regression() {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = "# day 1\npart_one = 24000\npart_two = \"45\\\"000\"  \n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45\"000"));
+
+        let answers: Answers = "part_two = '''\n##..\n..##\n'''\npart_one = 'CMZ'\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(1), Some("CMZ"));
+        assert_eq!(answers.get(2), Some("##..\n..##\n"));
+
+        assert!("part_three = 1".parse::<Answers>().is_err());
+        assert!("part_one = '''\nabc".parse::<Answers>().is_err());
+        assert!("part_one".parse::<Answers>().is_err());
+        assert_eq!(
+            "part_one = '''\nabc'''".parse::<Answers>().unwrap().get(1),
+            Some("abc")
+        );
+    }
+
+    #[test]
+    fn test_answers_to_toml() {
+        let answers = Answers {
+            part_one: Some(String::from("a \"b\"")),
+            part_two: Some(String::from("##..\n..##\n")),
+        };
+        assert_eq!(answers.to_toml().parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn test_find_conclusive_submission() {
+        let submission = |part, outcome, answer: &str| Submission {
+            part,
+            outcome,
+            answer: answer.to_string(),
+        };
+        let submissions = [
+            submission(1, SubmitOutcome::TooHigh, "500"),
+            submission(1, SubmitOutcome::TooLow, "100"),
+            submission(1, SubmitOutcome::Wait, "300"),
+            submission(2, SubmitOutcome::Incorrect, "ABC"),
+        ];
+
+        let find = |part, answer| Submission::find_conclusive(&submissions, part, answer);
+        assert_eq!(find(1, "500"), Some(&submissions[0]));
+        assert_eq!(find(1, "600"), Some(&submissions[0]));
+        assert_eq!(find(1, "50"), Some(&submissions[1]));
+        assert_eq!(find(1, "300"), None);
+        assert_eq!(find(1, "ABC"), None);
+        assert_eq!(find(2, "ABC"), Some(&submissions[3]));
+        assert_eq!(find(2, "600"), None);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answers = Answers {
+            part_one: Some(String::from("24000")),
+            part_two: None,
+        };
+        let mut result = PartResult {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: Some(String::from("24000")),
+            error: None,
+            elapsed: Duration::ZERO,
+        };
+
+        assert_eq!(Verdict::check(&result, Some(&answers)), Verdict::Correct);
+        assert_eq!(Verdict::check(&result, None), Verdict::Unknown);
+
+        result.answer = None;
+        assert_eq!(
+            Verdict::check(&result, Some(&answers)),
+            Verdict::Wrong {
+                expected: String::from("24000")
+            }
+        );
+
+        result.part = 2;
+        assert_eq!(Verdict::check(&result, Some(&answers)), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,255 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ffi::OsStr;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use std::process::{Command, Output};
+
+/// Name of the aoc-cli executable, looked up on `PATH`.
+pub const AOC_PROGRAM: &str = "aoc";
+
+#[derive(Debug)]
+pub enum AocCliError {
+    /// The executable could not be found or called.
+    NotInstalled,
+    /// The executable could be found but failed to start.
+    Spawn(io::Error),
+    /// The executable ran but exited with an error.
+    Failed,
+}
+
+impl Display for AocCliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocCliError::NotInstalled => write!(
+                f,
+                "command \"{}\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.",
+                AOC_PROGRAM
+            ),
+            AocCliError::Spawn(e) => write!(f, "failed to spawn aoc-cli: {}", e),
+            AocCliError::Failed => write!(f, "aoc-cli exited with an error"),
+        }
+    }
+}
+
+impl std::error::Error for AocCliError {}
+
+/// Checks that the given aoc-cli executable exists and is callable.
+pub fn check_installed(program: impl AsRef<OsStr>) -> Result<(), AocCliError> {
+    match Command::new(program).arg("-V").output() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(AocCliError::NotInstalled),
+    }
+}
+
+/// Builds the arguments which select a puzzle, to be followed by the aoc-cli sub-command.
+pub fn puzzle_args(day: u8, year: Option<i16>) -> Vec<String> {
+    let mut args = vec![];
+
+    if let Some(year) = year {
+        args.push("--year".into());
+        args.push(year.to_string());
+    }
+
+    args.append(&mut vec!["--day".into(), day.to_string()]);
+    args
+}
+
+/// Runs aoc-cli with the given arguments, echoing what it prints.  Returns its output if it
+/// exited successfully.
+pub fn call(program: impl AsRef<OsStr>, args: &[String]) -> Result<Output, AocCliError> {
+    let cmd_output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(AocCliError::Spawn)?;
+
+    io::stdout()
+        .write_all(&cmd_output.stdout)
+        .expect("could not write cmd stdout to pipe.");
+    io::stderr()
+        .write_all(&cmd_output.stderr)
+        .expect("could not write cmd stderr to pipe.");
+
+    if cmd_output.status.success() {
+        Ok(cmd_output)
+    } else {
+        Err(AocCliError::Failed)
+    }
+}
+
+/// The response of the Advent of Code website to a submitted answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// Submitted too soon after the previous answer; nothing was checked.
+    Wait,
+    /// The part was already solved, or is not unlocked yet; nothing was checked.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Classifies the text printed by `aoc submit`.  Returns `None` if it isn't recognized.
+    pub fn from_response(response: &str) -> Option<Self> {
+        let outcome = if response.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if response.contains("too high") {
+            SubmitOutcome::TooHigh
+        } else if response.contains("too low") {
+            SubmitOutcome::TooLow
+        } else if response.contains("That's not the right answer") {
+            SubmitOutcome::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            SubmitOutcome::Wait
+        } else if response.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(outcome)
+    }
+
+    /// The name used for the outcome in the submission log.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::TooHigh => "too-high",
+            SubmitOutcome::TooLow => "too-low",
+            SubmitOutcome::Incorrect => "incorrect",
+            SubmitOutcome::Wait => "wait",
+            SubmitOutcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    /// Reads an outcome from its name in the submission log.
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            SubmitOutcome::Correct,
+            SubmitOutcome::TooHigh,
+            SubmitOutcome::TooLow,
+            SubmitOutcome::Incorrect,
+            SubmitOutcome::Wait,
+            SubmitOutcome::WrongLevel,
+        ]
+        .into_iter()
+        .find(|o| o.name() == name)
+    }
+
+    /// Whether the website actually judged the answer.  Answers which weren't judged can be
+    /// submitted again.
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, SubmitOutcome::Wait | SubmitOutcome::WrongLevel)
+    }
+}
+
+/// Submits an answer for the given part through aoc-cli and classifies the response.
+pub fn submit(
+    program: impl AsRef<OsStr>,
+    day: u8,
+    year: Option<i16>,
+    part: u8,
+    answer: &str,
+) -> Result<Option<SubmitOutcome>, AocCliError> {
+    let mut args = puzzle_args(day, year);
+    args.append(&mut vec!["submit".into(), part.to_string(), answer.into()]);
+
+    println!("Submitting answer with >aoc {}", args.join(" "));
+    let output = call(program, &args)?;
+
+    Ok(SubmitOutcome::from_response(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_response() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Some(SubmitOutcome::Correct),
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Some(SubmitOutcome::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Some(SubmitOutcome::TooLow),
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                Some(SubmitOutcome::Incorrect),
+            ),
+            (
+                "You gave an answer too recently; you have 41s left to wait.",
+                Some(SubmitOutcome::Wait),
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Some(SubmitOutcome::WrongLevel),
+            ),
+            ("Loaded session cookie", None),
+        ];
+
+        for (response, expected) in cases {
+            assert_eq!(SubmitOutcome::from_response(response), expected);
+        }
+    }
+
+    #[test]
+    fn test_outcome_names() {
+        for outcome in [
+            SubmitOutcome::Correct,
+            SubmitOutcome::TooLow,
+            SubmitOutcome::Wait,
+        ] {
+            assert_eq!(SubmitOutcome::from_name(outcome.name()), Some(outcome));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_submit_with_stub() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("aoc_stub_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let stub = dir.join("aoc");
+        // Records its arguments so the test can check how it was called.
+        fs::write(
+            &stub,
+            "#!/bin/sh\necho \"$@\" > \"$(dirname \"$0\")/args\"\necho \"That's not the right answer; your answer is too low.\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+
+        // Found on `PATH` by name, like the real aoc-cli.  No other test runs `aoc`.
+        let path = std::env::var_os("PATH").unwrap_or_default();
+        let mut dirs = vec![dir.clone()];
+        dirs.extend(std::env::split_paths(&path));
+        std::env::set_var("PATH", std::env::join_paths(dirs).unwrap());
+
+        check_installed(AOC_PROGRAM).unwrap();
+        let outcome = submit(AOC_PROGRAM, 3, Some(2022), 2, "1234");
+        std::env::set_var("PATH", path);
+
+        assert_eq!(outcome.unwrap(), Some(SubmitOutcome::TooLow));
+        assert_eq!(
+            fs::read_to_string(dir.join("args")).unwrap().trim(),
+            "--year 2022 --day 3 submit 2 1234"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
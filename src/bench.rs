@@ -0,0 +1,287 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::results::{IntoAnswer, OutputFormat, PartResult};
+use crate::{ANSI_ITALIC, ANSI_RESET};
+
+/// Environment variable which enables benchmark mode.  Takes the same values as `--bench`.
+pub const BENCH_ENV_VAR: &str = "AOC_BENCH";
+
+/// Environment variable which, when set, also benchmarks parsing on its own.  Same as
+/// `--bench-parse`.
+pub const BENCH_PARSE_ENV_VAR: &str = "AOC_BENCH_PARSE";
+
+/// How long to keep re-running a solver for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Budget {
+    /// Run the solver exactly this many times.
+    Iterations(u32),
+    /// Keep running the solver until this much time has been spent in it.
+    Time(Duration),
+}
+
+impl FromStr for Budget {
+    type Err = String;
+
+    /// Parses either a number of iterations ("100") or a time budget with a unit ("500ms",
+    /// "2s", "1.5s", "750us").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(iterations) = s.parse::<u32>() {
+            return match iterations {
+                0 => Err(String::from("benchmark must run at least once")),
+                n => Ok(Budget::Iterations(n)),
+            };
+        }
+
+        let split = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| format!("invalid benchmark budget {:?}", s))?;
+        let (value, unit) = s.split_at(split);
+        let value: f64 = value
+            .parse()
+            .map_err(|_| format!("invalid benchmark budget {:?}", s))?;
+
+        let secs = match unit {
+            "s" => value,
+            "ms" => value / 1_000_f64,
+            "us" | "µs" => value / 1_000_000_f64,
+            _ => return Err(format!("unknown time unit {:?} (use s, ms or us)", unit)),
+        };
+
+        Ok(Budget::Time(Duration::from_secs_f64(secs)))
+    }
+}
+
+/// Settings for benchmark mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// How many times to run each part.
+    pub budget: Budget,
+    /// Whether to also benchmark the day's parser on its own.
+    pub time_parse: bool,
+}
+
+impl BenchConfig {
+    /// Reads `--bench <N|TIME>` and `--bench-parse` from the given arguments, consuming them,
+    /// falling back to the `AOC_BENCH` and `AOC_BENCH_PARSE` environment variables.  Returns
+    /// `None` if benchmark mode isn't enabled.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let budget = match args.opt_value_from_str::<_, Budget>("--bench")? {
+            Some(budget) => Some(budget),
+            None => match env::var(BENCH_ENV_VAR) {
+                Ok(v) => Some(
+                    v.parse()
+                        .map_err(|cause| pico_args::Error::ArgumentParsingFailed { cause })?,
+                ),
+                Err(_) => None,
+            },
+        };
+        let time_parse = args.contains("--bench-parse") || env::var(BENCH_PARSE_ENV_VAR).is_ok();
+
+        Ok(budget.map(|budget| BenchConfig { budget, time_parse }))
+    }
+
+    /// Like `from_args`, reading this process's command line.  Invalid values are reported and
+    /// ignored.
+    pub fn from_env() -> Option<Self> {
+        let mut args = pico_args::Arguments::from_env();
+        match BenchConfig::from_args(&mut args) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring benchmark settings: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Summary statistics over the durations of repeated runs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    /// How many times the code was run.
+    pub runs: usize,
+    /// The fastest run.
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The population standard deviation of the run times.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculates statistics over the given samples, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let runs = samples.len();
+        let mid = runs / 2;
+        let median = if runs.is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        let secs: Vec<f64> = samples.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / runs as f64;
+
+        Stats {
+            runs,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}",
+            self.median, self.min, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+/// Runs `func` repeatedly until the budget is used up, timing each run.  Returns the value of the
+/// first run along with the timings.
+pub fn measure<T>(budget: Budget, mut func: impl FnMut() -> T) -> (T, Stats) {
+    let mut samples = Vec::new();
+    let mut spent = Duration::ZERO;
+
+    let timer = Instant::now();
+    let first = black_box(func());
+    let elapsed = timer.elapsed();
+    samples.push(elapsed);
+    spent += elapsed;
+
+    loop {
+        let done = match budget {
+            Budget::Iterations(n) => samples.len() >= n as usize,
+            Budget::Time(time) => spent >= time,
+        };
+        if done {
+            break;
+        }
+
+        let timer = Instant::now();
+        black_box(func());
+        let elapsed = timer.elapsed();
+        samples.push(elapsed);
+        spent += elapsed;
+    }
+
+    (first, Stats::from_samples(&mut samples))
+}
+
+/// Benchmarks the given solver.  The returned result's `elapsed` is the median run time.
+pub fn bench_part<R: IntoAnswer>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+    budget: Budget,
+) -> (PartResult, Stats) {
+    let (outcome, stats) = measure(budget, || solver(black_box(input)));
+
+    (
+        PartResult::new(year, day, part, outcome, stats.median),
+        stats,
+    )
+}
+
+/// Prints a benchmarked result.  JSON output is the same as for a single run, with the median as
+/// the elapsed time.  If `parse` is given, the time spent outside of parsing is shown too.
+pub fn print_bench(
+    result: &PartResult,
+    stats: &Stats,
+    parse: Option<&Stats>,
+    format: OutputFormat,
+) {
+    if format == OutputFormat::Json {
+        result.print(format);
+        return;
+    }
+
+    match &result.answer {
+        Some(answer) => println!("{} {}({}){}", answer, ANSI_ITALIC, stats, ANSI_RESET),
+        None => print!("{}", result),
+    }
+
+    if let Some(parse) = parse {
+        println!("{}  parse: {}{}", ANSI_ITALIC, parse, ANSI_RESET);
+        println!(
+            "{}  solve (median minus parse): {:.2?}{}",
+            ANSI_ITALIC,
+            stats.median.saturating_sub(parse.median),
+            ANSI_RESET
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!("100".parse(), Ok(Budget::Iterations(100)));
+        assert_eq!("2s".parse(), Ok(Budget::Time(Duration::from_secs(2))));
+        assert_eq!(
+            "500ms".parse(),
+            Ok(Budget::Time(Duration::from_millis(500)))
+        );
+        assert_eq!(
+            "750us".parse(),
+            Ok(Budget::Time(Duration::from_micros(750)))
+        );
+        assert_eq!(
+            "1.5s".parse(),
+            Ok(Budget::Time(Duration::from_millis(1500)))
+        );
+        assert!("0".parse::<Budget>().is_err());
+        assert!("fast".parse::<Budget>().is_err());
+        assert!("10m".parse::<Budget>().is_err());
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples: Vec<_> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // sqrt(1.25) ms
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_measure_iterations() {
+        let mut calls = 0;
+        let (first, stats) = measure(Budget::Iterations(5), || {
+            calls += 1;
+            calls
+        });
+
+        assert_eq!(first, 1);
+        assert_eq!(calls, 5);
+        assert_eq!(stats.runs, 5);
+    }
+}
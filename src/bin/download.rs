@@ -2,21 +2,26 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
+use advent_of_code::aoc_cli::{self, AOC_PROGRAM};
+use advent_of_code::input::{input_path, year_dir, DEFAULT_YEAR};
+use std::env::temp_dir;
 use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: i16,
+    puzzle: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        puzzle: args.contains(["-p", "--puzzle"]),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
@@ -27,79 +32,89 @@ fn remove_file(path: &PathBuf) {
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
+fn exit_with_status(status: i32, paths: &[PathBuf]) -> ! {
+    paths.iter().for_each(remove_file);
     process::exit(status);
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
+    // acquire temp file paths to write aoc-cli output to.
+    // aoc-cli expects these files not to be present - delete just in case.
+    let tmp_input_path = temp_dir().join("aoc_input_tmp");
+    let tmp_puzzle_path = temp_dir().join("aoc_puzzle_tmp");
+    let tmp_paths = [tmp_input_path.clone(), tmp_puzzle_path.clone()];
+    tmp_paths.iter().for_each(remove_file);
 
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            exit_with_status(1, &tmp_paths);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_path = input_path(args.year, args.day);
+    let puzzle_path = year_dir(args.year)
+        .join("puzzles")
+        .join(format!("{:02}.md", args.day));
 
     // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    if let Err(e) = aoc_cli::check_installed(AOC_PROGRAM) {
+        eprintln!("{}", e);
+        exit_with_status(1, &tmp_paths);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
+    let mut cmd_args = vec![
         "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+        tmp_input_path.to_string_lossy().to_string(),
+    ];
+    if args.puzzle {
+        cmd_args.push("--puzzle-file".into());
+        cmd_args.push(tmp_puzzle_path.to_string_lossy().to_string());
+    }
+    cmd_args.append(&mut aoc_cli::puzzle_args(args.day, Some(args.year)));
+    cmd_args.push("download".into());
 
     println!("Downloading input with >aoc {}", cmd_args.join(" "));
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
+    match aoc_cli::call(AOC_PROGRAM, &cmd_args) {
+        Ok(_) => {}
+        Err(aoc_cli::AocCliError::Failed) => exit_with_status(1, &tmp_paths),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("{}", e);
+            exit_with_status(1, &tmp_paths);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+    for path in [&input_path, &puzzle_path] {
+        if let Some(Err(e)) = path.parent().map(fs::create_dir_all) {
+            eprintln!("could not create folder for \"{}\": {}", path.display(), e);
+            exit_with_status(1, &tmp_paths);
         }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+    }
+
+    if let Err(e) = fs::copy(&tmp_input_path, &input_path) {
+        eprintln!("could not copy downloaded input to input file: {}", e);
+        exit_with_status(1, &tmp_paths);
+    }
+
+    if args.puzzle {
+        if let Err(e) = fs::copy(&tmp_puzzle_path, &puzzle_path) {
+            eprintln!("could not copy downloaded puzzle to puzzle file: {}", e);
+            exit_with_status(1, &tmp_paths);
         }
     }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    if args.puzzle {
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            puzzle_path.display()
+        );
+    }
+    exit_with_status(0, &tmp_paths);
 }
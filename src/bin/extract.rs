@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::bin_name;
+use advent_of_code::input::{days_dir, src_dir, year_dir, DEFAULT_YEAR};
+use advent_of_code::puzzle::Puzzle;
+use std::path::Path;
+use std::{fs, process};
+
+struct Args {
+    year: i16,
+    day: u8,
+    all: bool,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        all: args.contains(["-a", "--all"]),
+        force: args.contains(["-f", "--force"]),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Writes an example file, unless it already has contents and `force` is not set.
+fn write_example(path: &Path, example: &str, force: bool) {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    if !existing.trim().is_empty() && !force {
+        println!(
+            "Skipped \"{}\" as it is not empty. Pass `--force` to overwrite it.",
+            path.display()
+        );
+        return;
+    }
+
+    match fs::write(path, example) {
+        Ok(_) => println!("Wrote example to \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write example file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Replaces the `None`/`Ok(None)` placeholders which `cargo scaffold` puts in the tests with the expected
+/// answers.  Only numeric answers are filled in, as other answer types vary between days.
+fn fill_tests(year: i16, day: u8, answers: &[Option<String>]) {
+    let candidates = [
+        days_dir(year).join(format!("day{:02}.rs", day)),
+        src_dir()
+            .join("bin")
+            .join(format!("{}.rs", bin_name(year, day))),
+    ];
+    let Some(module_path) = candidates.iter().find(|p| p.exists()) else {
+        return;
+    };
+    let Ok(mut module) = fs::read_to_string(module_path) else {
+        return;
+    };
+
+    let mut filled = false;
+    for (function, answer) in ["part_one", "part_two"].iter().zip(answers) {
+        let Some(answer) = answer.as_ref().filter(|a| a.parse::<i64>().is_ok()) else {
+            continue;
+        };
+        for (placeholder, expected) in [
+            ("None", format!("Some({})", answer)),
+            ("Ok(None)", format!("Ok(Some({}))", answer)),
+        ] {
+            let placeholder = format!("assert_eq!({}(&input), {});", function, placeholder);
+            let expected = format!("assert_eq!({}(&input), {});", function, expected);
+            if module.contains(&placeholder) {
+                module = module.replace(&placeholder, &expected);
+                filled = true;
+            }
+        }
+    }
+
+    if filled {
+        match fs::write(module_path, module) {
+            Ok(_) => println!(
+                "Filled in expected answers in \"{}\"",
+                module_path.display()
+            ),
+            Err(e) => eprintln!("Failed to update tests: {}", e),
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo extract 7`");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+    let puzzle_path = year_dir(args.year)
+        .join("puzzles")
+        .join(format!("{}.md", day_padded));
+    let year_flag = match args.year {
+        DEFAULT_YEAR => String::new(),
+        year => format!(" --year {}", year),
+    };
+
+    let markdown = match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) if !markdown.trim().is_empty() => markdown,
+        _ => {
+            eprintln!(
+                "Puzzle description \"{}\" is missing. Run `cargo download {} --puzzle{}` first.",
+                puzzle_path.display(),
+                args.day,
+                year_flag
+            );
+            process::exit(1);
+        }
+    };
+
+    let puzzle = Puzzle::parse(&markdown);
+    if puzzle.examples.is_empty() {
+        eprintln!(
+            "Could not find an example in \"{}\".",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    }
+
+    let count = if args.all { puzzle.examples.len() } else { 1 };
+    for (i, example) in puzzle.examples.iter().take(count).enumerate() {
+        let name = match i {
+            0 => format!("{}.txt", day_padded),
+            _ => format!("{}-{}.txt", day_padded, i + 1),
+        };
+        let path = year_dir(args.year).join("examples").join(name);
+        write_example(&path, example, args.force);
+    }
+    if !args.all && puzzle.examples.len() > 1 {
+        println!(
+            "Found {} more examples. Pass `--all` to write them too.",
+            puzzle.examples.len() - 1
+        );
+    }
+
+    for (i, answer) in puzzle.answers.iter().enumerate() {
+        match answer {
+            Some(answer) => println!("Proposed answer for part {}: {}", i + 1, answer),
+            None => println!("Could not find an answer for part {}.", i + 1),
+        }
+    }
+    fill_tests(args.year, args.day, &puzzle.answers);
+
+    println!("---");
+    println!("🎄 Type `cargo test` to check your solution against the examples.");
+}
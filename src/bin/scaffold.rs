@@ -2,113 +2,588 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::bin_name;
+use advent_of_code::input::days_dir;
+use advent_of_code::input::{input_path, src_dir, year_dir, DEFAULT_YEAR};
+use advent_of_code::puzzle::Puzzle;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::{Path, PathBuf},
+    process::{self, Command},
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// Library modules of the starter templates, which are followed by `TEMPLATE_TESTS`.
+const STARTERS: &[(&str, &str)] = &[
+    ("default", DEFAULT_STARTER),
+    ("lines", LINES_STARTER),
+    ("blocks", BLOCKS_STARTER),
+    ("grid", GRID_STARTER),
+];
+
+const DEFAULT_STARTER: &str = r###"//! {{title}}
+
+use crate::helpers::ParseError;
+
+pub fn part_one(_input: &str) -> Result<Option<u32>, ParseError> {
+    Ok(None)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(_input: &str) -> Result<Option<u32>, ParseError> {
+    Ok(None)
 }
+"###;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+const LINES_STARTER: &str = r###"//! {{title}}
+
+use crate::helpers::{parse_value, ParseError};
+
+/// Parses a number from every line of the input.
+pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_value(line, "a number").map_err(|e| e.locate(input)))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let _values = parse_input(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let _values = parse_input(input)?;
+    Ok(None)
+}
+"###;
+
+const BLOCKS_STARTER: &str = r###"//! {{title}}
+
+use crate::helpers::{blocks, parse_list, ParseError};
+
+/// Parses the blocks of the input, which are separated by blank lines, with a number per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    blocks(input)
+        .map(|block| parse_list(block, "\n", "a number").map_err(|e| e.locate(input)))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let _blocks = parse_input(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let _blocks = parse_input(input)?;
+    Ok(None)
+}
+"###;
+
+const GRID_STARTER: &str = r###"//! {{title}}
+
+use crate::helpers::{parse_grid, Grid, ParseError};
+
+/// Parses the input as a grid with a character per cell.  An empty input, such as an example which
+/// wasn't pasted yet, has no grid.
+pub fn parse_input(input: &str) -> Result<Option<Grid<char>>, ParseError> {
+    if input.trim().is_empty() {
+        return Ok(None);
+    }
+    parse_grid(input, Some, "a cell").map(Some)
 }
 
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let Some(_grid) = parse_input(input)? else {
+        return Ok(None);
+    };
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let Some(_grid) = parse_input(input)? else {
+        return Ok(None);
+    };
+    Ok(None)
+}
+"###;
+
+const TEMPLATE_TESTS: &str = r###"
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = crate::read_year_file({{year}}, "examples", {{day}});
+        assert_eq!(part_one(&input), Ok(None));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = crate::read_year_file({{year}}, "examples", {{day}});
+        assert_eq!(part_two(&input), Ok(None));
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+/// Module which registers the days of a year other than the `DEFAULT_YEAR`.
+const YEAR_MODULE_TEMPLATE: &str = r###"/*
+ * Solutions for the days of {{year}}, registered like those in `src/days/mod.rs`.
+ */
+
+days! {
+    year {{year}};
+}
+"###;
+
+struct Args {
+    year: i16,
+    day: u8,
+    template: String,
+    force: bool,
+    dry_run: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| String::from("default")),
+        force: args.contains(["-f", "--force"]),
+        dry_run: args.contains(["-n", "--dry-run"]),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Loads a template by name: `src/templates/<name>.rs` if it exists, else one of the `STARTERS`,
+/// else the file at the given path.
+fn load_template(name: &str) -> Result<String, String> {
+    let user_template = src_dir().join("templates").join(format!("{}.rs", name));
+    if let Ok(template) = fs::read_to_string(user_template) {
+        return Ok(template);
+    }
+
+    if let Some((_, starter)) = STARTERS.iter().find(|(starter, _)| *starter == name) {
+        return Ok(format!("{}{}", starter, TEMPLATE_TESTS));
+    }
+
+    fs::read_to_string(name).map_err(|_| {
+        let starters: Vec<_> = STARTERS.iter().map(|(starter, _)| *starter).collect();
+        format!(
+            "unknown template {:?}. Use one of {}, a file in src/templates or a path.",
+            name,
+            starters.join(", ")
+        )
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// Fills in the `{{year}}`, `{{day}}` and `{{title}}` placeholders of a template.
+fn render(template: &str, year: i16, day: u8, title: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", title)
+}
+
+/// Returns the title of the puzzle if its description was downloaded, or else "Day N".
+fn puzzle_title(puzzle_path: &Path, day: u8) -> String {
+    fs::read_to_string(puzzle_path)
+        .ok()
+        .and_then(|markdown| Puzzle::parse(&markdown).title)
+        .unwrap_or_else(|| format!("Day {}", day))
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+/// Creates the file if it doesn't exist yet, along with its folder, which is missing for a new
+/// year.
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
     OpenOptions::new().write(true).create(true).open(path)
 }
 
+/// Creates an empty data file unless it already exists, or reports what would be done.
+fn create_data_file(path: &Path, kind: &str, dry_run: bool) {
+    if dry_run {
+        if !path.exists() {
+            println!("Would create empty {} file \"{}\"", kind, path.display());
+        }
+        return;
+    }
+
+    match create_file(path) {
+        Ok(_) => {
+            println!("Created empty {} file \"{}\"", kind, path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create {} file: {}", kind, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Writes a source file, unless it exists and `force` is not set, or reports what would be written.
+fn write_source_file(path: &Path, contents: &str, kind: &str, args: &Args) {
+    if path.exists() && !args.force {
+        eprintln!(
+            "{} file \"{}\" already exists. Pass `--force` to overwrite it.",
+            kind,
+            path.display()
+        );
+        process::exit(1);
+    }
+
+    if args.dry_run {
+        println!("Would create {} file \"{}\":", kind, path.display());
+        println!("{}", contents);
+        return;
+    }
+
+    let file = match args.force {
+        true => File::create(path),
+        false => safe_create_file(path),
+    };
+    let result = file.and_then(|mut file| file.write_all(contents.as_bytes()));
+    match result {
+        Ok(_) => println!("Created {} file \"{}\"", kind, path.display()),
+        Err(e) => {
+            eprintln!("Failed to write {} file: {}", kind, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns the thin binary which runs the day's library module.
+fn bin_wrapper(year: i16, day: u8) -> String {
+    let (module, read_input) = match year {
+        DEFAULT_YEAR => (format!("day{:02}", day), format!("read_input({})", day)),
+        _ => (
+            format!("y{}::day{:02}", year, day),
+            format!("read_year_input({}, {})", year, day),
+        ),
+    };
+
+    format!(
+        r###"use advent_of_code::days::{}::{{part_one, part_two}};
+
+fn main() {{
+    let input = &advent_of_code::{};
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::answers::exit_on_regression();
+}}
+"###,
+        module, read_input
+    )
+}
+
+/// Returns the number of the day registered by a line of a `days!` list, e.g. "day01: 1,".
+fn registered_day(line: &str) -> Option<u8> {
+    let (_, rest) = line.trim().split_once(": ")?;
+    rest.split([',', ' ']).next()?.parse().ok()
+}
+
+/// Inserts an entry into the `days!` list at the end of the source, and declares the day's module
+/// with the others just above the list, keeping both ordered by day.  Returns `None` if the day is
+/// already registered or there is no list.
+fn insert_day(source: &str, day: u8, entry: &str) -> Option<String> {
+    let start = source.rfind("days! {")?;
+    let end = start + source[start..].find("\n}")?;
+
+    let mut lines: Vec<&str> = source[start..end].lines().collect();
+    if lines.iter().any(|line| registered_day(line) == Some(day)) {
+        return None;
+    }
+    let position = lines
+        .iter()
+        .position(|line| registered_day(line).is_some_and(|d| d > day))
+        .unwrap_or(lines.len());
+    lines.insert(position, entry);
+
+    let head: Vec<&str> = source[..start].trim_end().lines().collect();
+    let first_module = head
+        .iter()
+        .rposition(|line| !line.starts_with("pub mod day"))
+        .map_or(0, |i| i + 1);
+    let mut modules = head[first_module..].to_vec();
+    let declaration = format!("pub mod day{:02};", day);
+    if !modules.contains(&declaration.as_str()) {
+        let position = modules
+            .iter()
+            .position(|module| *module > declaration.as_str())
+            .unwrap_or(modules.len());
+        modules.insert(position, &declaration);
+    }
+
+    Some(format!(
+        "{}\n\n{}\n\n{}{}",
+        head[..first_module].join("\n").trim_end(),
+        modules.join("\n"),
+        lines.join("\n"),
+        &source[end..]
+    ))
+}
+
+/// Declares the module of a new year in `src/days/mod.rs` and adds it to `YEARS`, which is kept
+/// ordered by year.
+fn insert_year(source: &str, year: i16) -> Option<String> {
+    let module = format!("y{}", year);
+    if source.contains(&format!("pub mod {};", module)) {
+        return None;
+    }
+
+    let years = source.find("pub static YEARS")?;
+    let list = years + source[years..].find("= &[")? + "= &[".len();
+    let list_end = list + source[list..].find("];")?;
+    let declaration = source[..years].rfind("\n\n")? + 2;
+
+    // Entries are tuples such as `(DEFAULT_YEAR, ALL)` or `(2021, y2021::ALL)`.
+    let mut entries: Vec<String> = source[list..list_end]
+        .split(')')
+        .filter_map(|entry| Some(entry.split_once('(')?.1.trim()))
+        .map(|entry| format!("({})", entry))
+        .collect();
+    let entry_year = |entry: &str| match entry[1..].split(',').next().map(str::trim) {
+        Some("DEFAULT_YEAR") => Some(DEFAULT_YEAR),
+        Some(year) => year.parse().ok(),
+        None => None,
+    };
+    let position = entries
+        .iter()
+        .position(|entry| entry_year(entry).is_some_and(|y| y > year))
+        .unwrap_or(entries.len());
+    entries.insert(position, format!("({}, {}::ALL)", year, module));
+
+    Some(format!(
+        "{}pub mod {};\n\n{}{}{}",
+        &source[..declaration],
+        module,
+        &source[declaration..list],
+        entries.join(", "),
+        &source[list_end..]
+    ))
+}
+
+/// Applies an edit to a source file, or reports what it would change.  Returns whether the file
+/// changed.
+fn edit_source_file(
+    path: &Path,
+    description: &str,
+    dry_run: bool,
+    edit: impl FnOnce(&str) -> Option<String>,
+) -> bool {
+    let source = fs::read_to_string(path).unwrap_or_default();
+    let Some(edited) = edit(&source) else {
+        return false;
+    };
+
+    if dry_run {
+        println!("Would {} in \"{}\"", description, path.display());
+    } else if let Err(e) = fs::write(path, edited) {
+        eprintln!("Failed to {}: {}", description, e);
+        process::exit(1);
+    } else {
+        println!("Updated \"{}\" to {}", path.display(), description);
+    }
+    true
+}
+
+/// Registers the day's module in the `days!` list of its year, so `cargo all` runs it.  The list
+/// of a new year is created first.
+fn register_day(year: i16, day: u8, has_parser: bool, dry_run: bool) {
+    let days_mod = src_dir().join("days").join("mod.rs");
+    let year_mod = match year {
+        DEFAULT_YEAR => days_mod.clone(),
+        _ => days_dir(year).join("mod.rs"),
+    };
+
+    if !year_mod.exists() {
+        if dry_run {
+            println!("Would create year module \"{}\"", year_mod.display());
+        } else {
+            let contents = render(YEAR_MODULE_TEMPLATE, year, day, "");
+            if let Err(e) = fs::write(&year_mod, contents) {
+                eprintln!("Failed to create year module: {}", e);
+                process::exit(1);
+            }
+            println!("Created year module \"{}\"", year_mod.display());
+        }
+        let description = format!("add {} to the registered years", year);
+        edit_source_file(&days_mod, &description, dry_run, |source| {
+            insert_year(source, year)
+        });
+    }
+
+    let parser = if has_parser { " => parse_input" } else { "" };
+    let entry = format!("    day{:02}: {}{},", day, day, parser);
+    let description = format!("register day {} in `days!`", day);
+    let registered = edit_source_file(&year_mod, &description, dry_run, |source| {
+        insert_day(source, day, &entry)
+    });
+    if !registered && !dry_run {
+        println!(
+            "Day {} is already registered in \"{}\"",
+            day,
+            year_mod.display()
+        );
+    }
+}
+
+/// Makes sure git ignores the input, adding its folder to `.gitignore` if needed, as puzzle inputs
+/// must not be shared.
+fn ignore_input(input_path: &Path, dry_run: bool) {
+    let root = src_dir()
+        .parent()
+        .map_or_else(PathBuf::new, Path::to_path_buf);
+    let status = Command::new("git")
+        .args(["check-ignore", "-q"])
+        .arg(input_path)
+        .current_dir(&root)
+        .status();
+
+    // `git check-ignore` exits with 1 if the path isn't ignored, and with 128 outside of a
+    // repository.
+    if !matches!(status, Ok(status) if status.code() == Some(1)) {
+        return;
+    }
+    let Some(folder) = input_path.parent().and_then(|f| f.strip_prefix(&root).ok()) else {
+        return;
+    };
+
+    let pattern = format!("/{}/", folder.display());
+    let description = format!("ignore the inputs in \"{}\"", pattern);
+    edit_source_file(&root.join(".gitignore"), &description, dry_run, |source| {
+        let separator = if source.is_empty() || source.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        Some(format!("{}{}{}\n", source, separator, pattern))
+    });
+}
+
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
+    let (year, day) = (args.year, args.day);
 
     let day_padded = format!("{:02}", day);
+    let bin_name = bin_name(year, day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = input_path(year, day);
+    let example_path = year_dir(year)
+        .join("examples")
+        .join(format!("{}.txt", day_padded));
+    let puzzle_path = year_dir(year)
+        .join("puzzles")
+        .join(format!("{}.md", day_padded));
+    let module_path = days_dir(year).join(format!("day{}.rs", day_padded));
+    let bin_path = src_dir().join("bin").join(format!("{}.rs", bin_name));
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let template = match load_template(&args.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
     };
+    let module = render(&template, year, day, &puzzle_title(&puzzle_path, day));
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+    if !args.dry_run {
+        if let Err(e) = fs::create_dir_all(days_dir(year)) {
+            eprintln!("Failed to create module folder: {}", e);
             process::exit(1);
         }
     }
+    write_source_file(&module_path, &module, "module", &args);
+    write_source_file(&bin_path, &bin_wrapper(year, day), "binary", &args);
+    register_day(
+        year,
+        day,
+        module.contains("pub fn parse_input("),
+        args.dry_run,
+    );
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
-        }
+    create_data_file(&input_path, "input", args.dry_run);
+    create_data_file(&example_path, "example", args.dry_run);
+    create_data_file(&puzzle_path, "puzzle", args.dry_run);
+    ignore_input(&input_path, args.dry_run);
+
+    if !args.dry_run {
+        println!("---");
+        println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_MOD: &str = "use crate::input::DEFAULT_YEAR;
+
+/// The solutions of every year, ordered by year.
+pub static YEARS: &[(i16, &[&dyn Solution])] = &[(DEFAULT_YEAR, ALL)];
+
+pub mod day01;
+pub mod day03;
+
+days! {
+    year DEFAULT_YEAR;
+    day01: 1 => parse_input,
+    day03: 3,
+}
+";
+
+    #[test]
+    fn test_insert_day() {
+        let source = insert_day(DAYS_MOD, 2, "    day02: 2,").unwrap();
+        assert!(source.contains("    day01: 1 => parse_input,\n    day02: 2,\n    day03: 3,\n}"));
+        assert!(source.contains("= &[(DEFAULT_YEAR, ALL)];\n\npub mod day01;\npub mod day02;\n"));
+
+        let source = insert_day(&source, 4, "    day04: 4,").unwrap();
+        assert!(source.contains("    day03: 3,\n    day04: 4,\n}"));
+        assert!(source.contains("pub mod day03;\npub mod day04;\n\ndays! {"));
+
+        assert_eq!(insert_day(DAYS_MOD, 3, "    day03: 3,"), None);
+
+        // The list of a new year has no modules yet.
+        let year_mod = render(YEAR_MODULE_TEMPLATE, 2021, 7, "");
+        let source = insert_day(&year_mod, 7, "    day07: 7,").unwrap();
+        assert!(
+            source.contains(" */\n\npub mod day07;\n\ndays! {\n    year 2021;\n    day07: 7,\n}")
+        );
     }
 
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    #[test]
+    fn test_insert_year() {
+        let source = insert_year(DAYS_MOD, DEFAULT_YEAR - 1).unwrap();
+        let source = insert_year(&source, DEFAULT_YEAR + 1).unwrap();
+        let source = insert_year(&source, DEFAULT_YEAR - 2).unwrap();
+
+        let (before, after) = (DEFAULT_YEAR - 1, DEFAULT_YEAR + 1);
+        assert!(source.contains(&format!(
+            "= &[({0}, y{0}::ALL), ({1}, y{1}::ALL), (DEFAULT_YEAR, ALL), ({2}, y{2}::ALL)];",
+            DEFAULT_YEAR - 2,
+            before,
+            after
+        )));
+        assert!(source.starts_with(&format!(
+            "use crate::input::DEFAULT_YEAR;\n\npub mod y{};\n\n",
+            before
+        )));
+        assert_eq!(insert_year(&source, before), None);
+    }
 }
@@ -0,0 +1,140 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Submission};
+use advent_of_code::aoc_cli::{self, SubmitOutcome, AOC_PROGRAM};
+use advent_of_code::days;
+use advent_of_code::input::{input_path, InputSource, DEFAULT_YEAR};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // Options are read first, so they can't be mistaken for the day or part.
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(DEFAULT_YEAR);
+
+    let parsed = Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year,
+    };
+
+    let remaining = args.finish();
+    if let Some(arg) = remaining.first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", arg),
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Runs the day's registered solution on its input and returns the answer for the given part.
+fn solve_part(year: i16, day: u8, part: u8) -> Option<String> {
+    let Some(solution) = days::get(year, day) else {
+        eprintln!("Day {} is not registered in `days!`.", day);
+        return None;
+    };
+    let input = match InputSource::File(input_path(year, day)).read(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read the input: {}", e);
+            return None;
+        }
+    };
+
+    let result = solution.run(part, &input);
+    if let Some(error) = &result.error {
+        eprintln!("Day {} part {} failed: {}", day, part, error);
+    }
+
+    result.answer
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        result => {
+            if let Err(e) = result {
+                eprintln!("{}", e);
+            }
+            eprintln!("Need to specify a day and a part (1 or 2). example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    // check if aoc binary exists and is callable.
+    if let Err(e) = aoc_cli::check_installed(AOC_PROGRAM) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let answer = match solve_part(args.year, args.day, args.part) {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Day {} part {} is not solved.", args.day, args.part);
+            process::exit(1);
+        }
+    };
+    println!(
+        "🎄 Answer for day {} part {}: {}",
+        args.day, args.part, answer
+    );
+
+    let submissions = Submission::load_all(args.year, args.day);
+    if let Some(previous) = Submission::find_conclusive(&submissions, args.part, &answer) {
+        eprintln!(
+            "Not submitting: \"{}\" was already submitted and was {}.",
+            previous.answer,
+            previous.outcome.name()
+        );
+        process::exit(1);
+    }
+
+    let outcome = match aoc_cli::submit(AOC_PROGRAM, args.day, Some(args.year), args.part, &answer)
+    {
+        Ok(Some(outcome)) => outcome,
+        Ok(None) => {
+            eprintln!("could not understand the response to the submission.");
+            process::exit(1);
+        }
+        Err(aoc_cli::AocCliError::Failed) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let submission = Submission {
+        part: args.part,
+        outcome,
+        answer: answer.clone(),
+    };
+    if let Err(e) = submission.record(args.year, args.day) {
+        eprintln!("could not record submission: {}", e);
+    }
+
+    println!("---");
+    if outcome == SubmitOutcome::Correct {
+        let mut answers = Answers::load(args.year, args.day).unwrap_or_default();
+        answers.set(args.part, answer);
+        match answers.save(args.year, args.day) {
+            Ok(path) => println!("🎄 Correct! Recorded answer in \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("could not record answer: {}", e);
+                process::exit(1);
+            }
+        }
+    } else {
+        println!("🎄 Answer was {}.", outcome.name());
+        process::exit(1);
+    }
+}
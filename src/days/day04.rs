@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use crate::helpers::{integers_n, parse_lines, ParseError};
+
+/// Represents a number range with a min and a max value.
+struct NumRange {
+    min: u32,
+    max: u32,
+}
+
+impl NumRange {
+    /// Whether or not the given range overlaps with the current one.
+    pub fn overlaps(&self, other: &NumRange) -> bool {
+        self.min <= other.max && other.min <= self.max
+    }
+
+    /// Whether or not the given range is completely contained within the current one.
+    pub fn contains(&self, other: &NumRange) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+}
+
+/// Represents a pair or ranges, one for each elf, as per the input.
+struct RangePair {
+    elf1_range: NumRange,
+    elf2_range: NumRange,
+}
+
+impl FromStr for RangePair {
+    type Err = ParseError;
+
+    /// Parses a RangePair in the form {min}-{max},{min}-{max}
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [min1, max1, min2, max2] = integers_n(s)?;
+
+        Ok(RangePair {
+            elf1_range: NumRange {
+                min: min1,
+                max: max1,
+            },
+            elf2_range: NumRange {
+                min: min2,
+                max: max2,
+            },
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    let fully_overlapping_ranges = parse_lines::<RangePair>(input)?
+        .iter()
+        .filter(|p| p.elf1_range.contains(&p.elf2_range) || p.elf2_range.contains(&p.elf1_range))
+        .count();
+
+    Ok(Some(fully_overlapping_ranges))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    let overlapping_ranges = parse_lines::<RangePair>(input)?
+        .iter()
+        .filter(|p| p.elf1_range.overlaps(&p.elf2_range))
+        .count();
+
+    Ok(Some(overlapping_ranges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_one(&input), Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_two(&input), Ok(Some(4)));
+    }
+}
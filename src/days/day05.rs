@@ -0,0 +1,141 @@
+use std::str::FromStr;
+
+use crate::helpers::{integers_n, parse_lines, ParseError};
+
+#[derive(Debug)]
+struct Stacks {
+    stacks: Vec<Vec<char>>,
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stacks = Vec::new();
+
+        let mut input = s.lines().rev();
+        _ = input
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "the line of stack numbers"))?; // Eat the line of numbers; we don't need it
+
+        for line in input {
+            for (stack_idx, value_idx) in (1..line.len()).step_by(4).enumerate() {
+                if stack_idx >= stacks.len() {
+                    stacks.push(Vec::new());
+                }
+                let ch = line
+                    .chars()
+                    .nth(value_idx)
+                    .ok_or_else(|| ParseError::new(line, "a crate or a space"))?;
+                if ch != ' ' {
+                    let stack = &mut stacks[stack_idx];
+                    stack.push(ch);
+                }
+            }
+        }
+
+        Ok(Self { stacks })
+    }
+}
+
+impl Stacks {
+    pub fn execute_9000(&mut self, instr: &Instruction) {
+        for _ in 0..instr.num {
+            let ch = self.stacks[instr.from - 1].pop().unwrap();
+            self.stacks[instr.to - 1].push(ch);
+        }
+    }
+
+    pub fn execute_9001(&mut self, instr: &Instruction) {
+        assert!(instr.from != instr.to);
+        let from = instr.from - 1;
+        let to = instr.to - 1;
+        let from_len = self.stacks[from].len();
+
+        for idx in (from_len - instr.num)..from_len {
+            let ch = self.stacks[from][idx];
+            self.stacks[to].push(ch);
+        }
+
+        for _ in 0..instr.num {
+            self.stacks[from].pop();
+        }
+    }
+
+    pub fn get_message(&self) -> String {
+        let mut str = String::new();
+        for s in &self.stacks {
+            str.push(*s.last().unwrap());
+        }
+
+        str
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Instruction {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [num, from, to] = integers_n(s)?;
+
+        Ok(Self { num, from, to })
+    }
+}
+
+/// Parses the drawing of the stacks and the instructions which follow it after a blank line.
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Instruction>), ParseError> {
+    let (stack_data, instruction_data) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(input, "a blank line and the instructions"))?;
+
+    let stacks = stack_data.parse::<Stacks>().map_err(|e| e.locate(input))?;
+    let instructions = parse_lines::<Instruction>(instruction_data).map_err(|e| e.locate(input))?;
+
+    Ok((stacks, instructions))
+}
+
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    let (mut stacks, instructions) = parse_input(input)?;
+
+    for i in &instructions {
+        stacks.execute_9000(i);
+    }
+
+    let message = stacks.get_message();
+    Ok(Some(message))
+}
+
+pub fn part_two(input: &str) -> Result<Option<String>, ParseError> {
+    let (mut stacks, instructions) = parse_input(input)?;
+
+    for i in &instructions {
+        stacks.execute_9001(i);
+    }
+
+    let message = stacks.get_message();
+    Ok(Some(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 5);
+        assert_eq!(part_one(&input), Ok(Some(String::from("CMZ"))));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 5);
+        assert_eq!(part_two(&input), Ok(Some(String::from("MCD"))));
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+fn find_marker(sequence: &Vec<char>, seq_len: usize) -> Option<usize> {
+    for i in 0..sequence.len() - seq_len {
+        let map: HashSet<char> = sequence[i..i + seq_len].iter().map(|i| *i).collect();
+        if map.len() == seq_len {
+            return Some(i + seq_len);
+        }
+    }
+
+    None
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let chars: Vec<char> = input.chars().collect();
+    find_marker(&chars, 4)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let chars: Vec<char> = input.chars().collect();
+    find_marker(&chars, 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        crate::test_examples!(6, part_one, [
+            "" => Some(7),
+            "b" => Some(5),
+            "c" => Some(6),
+            "d" => Some(11),
+        ]);
+    }
+
+    #[test]
+    fn test_part_two() {
+        crate::test_examples!(6, part_two, [
+            "" => Some(19),
+            "b" => Some(23),
+            "c" => Some(23),
+            "d" => Some(26),
+        ]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::helpers::{self, Grid, Vector2i, CARDINAL_DIRS};
+
+/// Marks the trees in the line which are visible from either end of it: those taller than every
+/// tree before them.
+fn mark_visible<'a>(
+    line: impl DoubleEndedIterator<Item = (Vector2i, &'a u32)> + Clone,
+    visibility_grid: &mut Grid<bool>,
+) {
+    let mut scan = |trees: &mut dyn Iterator<Item = (Vector2i, &'a u32)>| {
+        let mut tallest = None;
+        for (pos, &height) in trees {
+            if tallest.is_none_or(|tallest| height > tallest) {
+                tallest = Some(height);
+                visibility_grid[pos] = true;
+            }
+        }
+    };
+
+    scan(&mut line.clone());
+    scan(&mut line.rev());
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let treemap = helpers::grid_from_digit_grid(input);
+
+    let mut visibility_grid = Grid::new_empty(treemap.width(), treemap.height(), false);
+    for row in treemap.rows() {
+        mark_visible(row, &mut visibility_grid);
+    }
+    for col in treemap.cols() {
+        mark_visible(col, &mut visibility_grid);
+    }
+
+    let visible_trees = visibility_grid.iter().filter(|&l| *l).count();
+
+    Some(visible_trees)
+}
+
+/// Counts the trees which can be seen from the given position in the given direction, up to and
+/// including the first one which is at least as tall.
+fn viewing_distance(treemap: &Grid<u32>, pos: Vector2i, dir: Vector2i) -> u32 {
+    let height = treemap[pos];
+    let mut distance = 0;
+    for (_, &tree) in treemap.ray(pos, dir) {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let treemap = helpers::grid_from_digit_grid(input);
+
+    // This is somewhat brute force, but works for our input sizes.
+    treemap
+        .positions()
+        .map(|pos| {
+            CARDINAL_DIRS
+                .iter()
+                .map(|&dir| viewing_distance(&treemap, pos, dir))
+                .product()
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
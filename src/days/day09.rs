@@ -0,0 +1,118 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::helpers::{parse_lines, parse_value, ParseError, Vector2i, DOWN, LEFT, RIGHT, UP};
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Instruction {
+    direction: Vector2i,
+    amount: u32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(' ');
+        let direction_data = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "a direction"))?;
+        let amount_data = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "' ' and a number of steps"))?;
+
+        let direction = match direction_data {
+            "U" => UP,
+            "R" => RIGHT,
+            "D" => DOWN,
+            "L" => LEFT,
+            _ => return Err(ParseError::new(direction_data, "'U', 'R', 'D' or 'L'")),
+        };
+        let amount: u32 = parse_value(amount_data, "a number of steps")?;
+
+        Ok(Self { direction, amount })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
+}
+
+/// Given the position of a "leader" knot (the knot in front), calculate the new position of the
+/// trailing knot.
+fn get_knot_follow_pos(leader_knot: &Vector2i, trailing_knot: &Vector2i) -> Vector2i {
+    // Knots which touch, diagonally included, don't move.  Otherwise the trailing knot takes a
+    // single step towards the leader, diagonally if they're on different rows and columns.
+    if leader_knot.chebyshev_distance(trailing_knot) <= 1 {
+        return *trailing_knot;
+    }
+
+    trailing_knot + (leader_knot - trailing_knot).signum()
+}
+
+/// Simulates a rope with the given number of knots (including the "head" and "tail").
+///
+fn simulate_rope<'a>(num_knots: u32, instructions: impl Iterator<Item = &'a Instruction>) -> usize {
+    // Keep track of all rope positions.  We'll assume they all start at (0, 0); the actual positions
+    // don't matter (only the relative positions of the knots to each other does) since all we care
+    // about is the number of positions the tail visits.
+    //
+    // 0 will be the head; knots.len() - 1 will be the tail.
+    let mut knots = Vec::new();
+    for _ in 0..num_knots {
+        knots.push(Vector2i { x: 0, y: 0 });
+    }
+
+    // Track how many positions the tail visits
+    let mut tail_visited = HashSet::new();
+    tail_visited.insert(knots[knots.len() - 1]);
+
+    for instr in instructions {
+        for _ in 0..instr.amount {
+            // Advance the head.
+            knots[0] += instr.direction;
+
+            // Advance every other rope according to the one ahead of it
+            for idx in 1..knots.len() {
+                knots[idx] = get_knot_follow_pos(&knots[idx - 1], &knots[idx]);
+            }
+
+            // Ensure we mark the position the tail is now at appropriately
+            tail_visited.insert(knots[knots.len() - 1]);
+        }
+    }
+
+    tail_visited.len()
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    let instructions = parse_input(input)?;
+    let num_positions = simulate_rope(2, instructions.iter());
+
+    Ok(Some(num_positions))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    let instructions = parse_input(input)?;
+    let num_positions = simulate_rope(10, instructions.iter());
+
+    Ok(Some(num_positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 9);
+        assert_eq!(part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        crate::test_examples!(9, part_two, [
+            "" => Ok(Some(1)),
+            "larger" => Ok(Some(36)),
+        ]);
+    }
+}
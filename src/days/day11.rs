@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use crate::helpers::{integers, lcm, parse_blocks, parse_value, scan, ParseError};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Operation {
+    AddConst(u64),
+    AddOld,
+    MultConst(u64),
+    MultOld,
+}
+
+#[derive(Debug)]
+struct Monkey {
+    id: usize,
+    current_items: Vec<u64>,
+    operation: Operation,
+    test_divisor: u64,
+    true_dest: usize,
+    false_dest: usize,
+    items_inspected: u64,
+}
+
+const MONKEY_PATTERN: &str = "Monkey {}:
+  Starting items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}";
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = scan(s, MONKEY_PATTERN)?;
+
+        let (op, operand) = (values[2], values[3]);
+        let constant = || parse_value(operand, "'old' or a number");
+        let operation = match (op, operand) {
+            ("+", "old") => Operation::AddOld,
+            ("+", _) => Operation::AddConst(constant()?),
+            ("*", "old") => Operation::MultOld,
+            ("*", _) => Operation::MultConst(constant()?),
+            _ => return Err(ParseError::new(op, "'+' or '*'")),
+        };
+
+        Ok(Self {
+            id: parse_value(values[0], "a monkey number")?,
+            current_items: integers(values[1]).collect::<Result<_, _>>()?,
+            operation,
+            test_divisor: parse_value(values[4], "a divisor")?,
+            true_dest: parse_value(values[5], "a monkey number")?,
+            false_dest: parse_value(values[6], "a monkey number")?,
+            items_inspected: 0,
+        })
+    }
+}
+
+fn simulate_monkey_business(
+    monkeys: &mut Vec<Monkey>,
+    rounds: u32,
+    worry_control: impl Fn(u64) -> u64,
+) {
+    for _ in 0..rounds {
+        for monkey_idx in 0..monkeys.len() {
+            assert!(monkeys[monkey_idx].id == monkey_idx);
+
+            for item_idx in 0..monkeys[monkey_idx].current_items.len() {
+                // Record that this monkey inspected an item
+                monkeys[monkey_idx].items_inspected += 1;
+
+                let mut item = monkeys[monkey_idx].current_items[item_idx];
+
+                // Incrase worry level
+                item = match monkeys[monkey_idx].operation {
+                    Operation::AddConst(c) => item + c,
+                    Operation::AddOld => item + item,
+                    Operation::MultConst(c) => item * c,
+                    Operation::MultOld => item * item,
+                };
+
+                // Apply worry control
+                item = worry_control(item);
+
+                // Figure out who we throw the item to
+                let throw_to = if item % monkeys[monkey_idx].test_divisor == 0 {
+                    monkeys[monkey_idx].true_dest
+                } else {
+                    monkeys[monkey_idx].false_dest
+                };
+
+                // Throw to the proper monkey
+                monkeys[throw_to].current_items.push(item);
+            }
+            monkeys[monkey_idx].current_items.clear();
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>, ParseError> {
+    let mut monkeys: Vec<Monkey> = parse_blocks(input)?;
+
+    simulate_monkey_business(&mut monkeys, 20, |w| w / 3);
+
+    let mut items_inspected: Vec<_> = monkeys.iter().map(|m| m.items_inspected).collect();
+    items_inspected.sort();
+    Ok(Some(
+        items_inspected[items_inspected.len() - 2] * items_inspected[items_inspected.len() - 1],
+    ))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseError> {
+    let mut monkeys: Vec<Monkey> = parse_blocks(input)?;
+
+    // Calculate the least common multiple of all the test divisors so we can avoid infinite growth.
+    // This is safe because all the algorithm does with the worry value is divide by the test_divisor,
+    // and look at the remainder, so wrapping at the lcm of all the values won't hurt.
+    let least_common_multiple = monkeys
+        .iter()
+        .map(|m| m.test_divisor)
+        .fold(1, |accum, elem| lcm(accum, elem));
+
+    simulate_monkey_business(&mut monkeys, 10000, |w| w % least_common_multiple);
+
+    let mut items_inspected: Vec<_> = monkeys.iter().map(|m| m.items_inspected).collect();
+    items_inspected.sort();
+    Ok(Some(
+        items_inspected[items_inspected.len() - 2] * items_inspected[items_inspected.len() - 1],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 11);
+        assert_eq!(part_one(&input), Ok(Some(10605)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 11);
+        assert_eq!(part_two(&input), Ok(Some(2713310158)));
+    }
+}
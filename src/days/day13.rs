@@ -0,0 +1,190 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::helpers::{parse_blocks, parse_value, ParseError};
+
+#[derive(Clone, Debug)]
+enum Element {
+    List(Vec<Element>),
+    Integer(u32),
+}
+
+fn compare_elements(e1: &Element, e2: &Element) -> Ordering {
+    match e1 {
+        Element::List(l1) => match e2 {
+            Element::List(l2) => compare_lists(l1, l2),
+            Element::Integer(_) => {
+                let l2 = Element::List(vec![e2.clone()]);
+                compare_elements(e1, &l2)
+            }
+        },
+        Element::Integer(i1) => match e2 {
+            Element::Integer(i2) => i1.cmp(i2),
+            Element::List(_) => {
+                let l1 = Element::List(vec![e1.clone()]);
+                compare_elements(&l1, e2)
+            }
+        },
+    }
+}
+
+fn compare_lists(l1: &Vec<Element>, l2: &Vec<Element>) -> Ordering {
+    for (e1, e2) in l1.iter().zip(l2) {
+        let compare = compare_elements(e1, e2);
+        if compare != Ordering::Equal {
+            return compare;
+        };
+    }
+
+    match l1.len() as i32 - l2.len() as i32 {
+        i32::MIN..=-1 => Ordering::Less,
+        0 => Ordering::Equal,
+        1..=i32::MAX => Ordering::Greater,
+    }
+}
+
+/// Parses the rest of a list from the characters (and their indices) of `s`, after its opening
+/// '['.
+fn parse_list_recursive(
+    s: &str,
+    it: &mut impl Iterator<Item = (usize, char)>,
+) -> Result<Vec<Element>, ParseError> {
+    let mut list = Vec::new();
+    loop {
+        let (mut idx, mut ch) = it.next().ok_or_else(|| ParseError::missing(s, "']'"))?;
+        match ch {
+            ',' => {}
+            ']' => return Ok(list),
+            '[' => list.push(Element::List(parse_list_recursive(s, it)?)),
+            _ => {
+                let start = idx;
+                while ch.is_digit(10) {
+                    (idx, ch) = it.next().ok_or_else(|| ParseError::missing(s, "']'"))?;
+                }
+                if idx == start {
+                    let unexpected = &s[idx..idx + ch.len_utf8()];
+                    return Err(ParseError::new(unexpected, "a number, ',', '[' or ']'"));
+                }
+
+                list.push(Element::Integer(parse_value(&s[start..idx], "a number")?));
+                if ch == ']' {
+                    return Ok(list);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Packet {
+    elements: Vec<Element>,
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.char_indices();
+        if it.next().map(|(_, ch)| ch) != Some('[') {
+            return Err(ParseError::new(s, "'['"));
+        }
+        Ok(Self {
+            elements: parse_list_recursive(s, &mut it)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Pair {
+    p1: Packet,
+    p2: Packet,
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines_it = s.lines();
+
+        let p1 = lines_it
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "a packet"))?
+            .parse::<Packet>()?;
+        let p2 = lines_it
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "a second packet"))?
+            .parse::<Packet>()?;
+
+        Ok(Self { p1, p2 })
+    }
+}
+
+impl Pair {
+    pub fn is_right_order(&self) -> bool {
+        match compare_lists(&self.p1.elements, &self.p2.elements) {
+            Ordering::Less => true,
+            Ordering::Greater => false,
+            Ordering::Equal => panic!("Tie can't happen in pairs."),
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    let pairs = parse_blocks::<Pair>(input)?;
+
+    let mut sum = 0;
+    for (idx, pair) in pairs.iter().enumerate() {
+        if pair.is_right_order() {
+            sum += idx + 1;
+        }
+    }
+
+    Ok(Some(sum))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    let mut packets = input
+        .lines()
+        .filter(|&i| i != "")
+        .map(|l| {
+            l.parse::<Packet>()
+                .map(|p| p.elements)
+                .map_err(|e| e.locate(input))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let divider1 = vec![Element::List(vec![Element::Integer(2)])];
+    let divider2 = vec![Element::List(vec![Element::Integer(6)])];
+    packets.push(divider1.clone());
+    packets.push(divider2.clone());
+
+    packets.sort_by(compare_lists);
+
+    let s1_idx = packets
+        .iter()
+        .position(|i| compare_lists(&divider1, i) == Ordering::Equal)
+        .unwrap();
+
+    let s2_idx = packets
+        .iter()
+        .position(|i| compare_lists(&divider2, i) == Ordering::Equal)
+        .unwrap();
+
+    Ok(Some((s1_idx + 1) * (s2_idx + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 13);
+        assert_eq!(part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 13);
+        assert_eq!(part_two(&input), Ok(Some(140)));
+    }
+}
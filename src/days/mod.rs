@@ -0,0 +1,153 @@
+/*
+ * Solutions for each day live in this module, so that both the per-day binaries in `src/bin/` and
+ * the `all` runner in `src/main.rs` can call them.
+ *
+ * To make a day available to the runner, declare its module and add it to the `days!` list at
+ * the bottom of this file.  If the module has a function which only parses the input, it can be
+ * registered after a `=>` so benchmarks can time it separately.
+ *
+ * Days of other years than `DEFAULT_YEAR` live in a submodule per year, e.g. `y2021/mod.rs` with
+ * its own modules and `days!` list, which is added to `YEARS`.
+ */
+use crate::bench::{Budget, Stats};
+use crate::input::DEFAULT_YEAR;
+use crate::results::PartResult;
+
+/// A day's solution, callable without knowing the types of its answers.
+pub trait Solution: Sync {
+    /// Returns the year of the puzzle this solves.
+    fn year(&self) -> i16;
+
+    /// Returns the day of the puzzle this solves.
+    fn day(&self) -> u8;
+
+    /// Runs the given part (1 or 2) of the solution against the input, timing the solver.
+    fn run(&self, part: u8, input: &str) -> PartResult;
+
+    /// Runs the given part repeatedly, as in `bench::bench_part`.
+    fn bench(&self, part: u8, input: &str, budget: Budget) -> (PartResult, Stats);
+
+    /// Benchmarks only the day's parser.  Returns `None` if the day has no registered parser.
+    fn bench_parse(&self, input: &str, budget: Budget) -> Option<Stats>;
+}
+
+/// Implements `Solution` for the day with the given year and number.  Instances are created by the
+/// `days!` macro.
+pub struct Day<const YEAR: i16, const DAY: u8>;
+
+/// Registers the `part_one`/`part_two` functions (and optionally the parser) of each day's module
+/// as a `Solution`.  The modules are declared outside the macro, so rustfmt can find them.
+macro_rules! days {
+    (@parser $module:ident $parser:ident) => {{
+        // Parsers may return a `Result`, which is only timed here.
+        let parse: fn(&str) = |input| {
+            let _ = std::hint::black_box($module::$parser(input));
+        };
+        Some(parse)
+    }};
+    (@parser $module:ident) => {
+        None::<fn(&str)>
+    };
+    (year $year:expr; $($module:ident: $day:literal $(=> $parser:ident)?),* $(,)?) => {
+        $(
+            impl $crate::days::Solution for $crate::days::Day<{ $year }, $day> {
+                fn year(&self) -> i16 {
+                    $year
+                }
+
+                fn day(&self) -> u8 {
+                    $day
+                }
+
+                fn run(&self, part: u8, input: &str) -> $crate::results::PartResult {
+                    match part {
+                        1 => $crate::run_part($year, $day, part, $module::part_one, input),
+                        2 => $crate::run_part($year, $day, part, $module::part_two, input),
+                        _ => panic!("day {} has no part {}", $day, part),
+                    }
+                }
+
+                fn bench(
+                    &self,
+                    part: u8,
+                    input: &str,
+                    budget: $crate::bench::Budget,
+                ) -> ($crate::results::PartResult, $crate::bench::Stats) {
+                    use $crate::bench::bench_part;
+                    match part {
+                        1 => bench_part($year, $day, part, $module::part_one, input, budget),
+                        2 => bench_part($year, $day, part, $module::part_two, input, budget),
+                        _ => panic!("day {} has no part {}", $day, part),
+                    }
+                }
+
+                fn bench_parse(
+                    &self,
+                    input: &str,
+                    budget: $crate::bench::Budget,
+                ) -> Option<$crate::bench::Stats> {
+                    let parser = days!(@parser $module $($parser)?);
+                    parser.map(|parse| {
+                        $crate::bench::measure(budget, || parse(std::hint::black_box(input))).1
+                    })
+                }
+            }
+        )*
+
+        /// The solutions for every registered day of the year, ordered by day.
+        pub static ALL: &[&dyn $crate::days::Solution] = &[$(&$crate::days::Day::<{ $year }, $day>),*];
+    };
+}
+
+/// The solutions of every year, ordered by year.
+pub static YEARS: &[(i16, &[&dyn Solution])] = &[(DEFAULT_YEAR, ALL)];
+
+/// Returns the solutions registered for the given year, ordered by day.
+pub fn year(year: i16) -> &'static [&'static dyn Solution] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, solutions)| solutions)
+}
+
+/// Returns the solution for the given day, if one is registered.
+pub fn get(year: i16, day: u8) -> Option<&'static dyn Solution> {
+    self::year(year).iter().find(|s| s.day() == day).copied()
+}
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+days! {
+    year DEFAULT_YEAR;
+    day01: 1 => parse_input,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12 => parse_input,
+    day13: 13,
+    day14: 14 => parse_input,
+    day15: 15,
+    day16: 16,
+}
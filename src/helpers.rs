@@ -5,14 +5,18 @@
 
 use std::{
     fmt::Display,
-    ops::{self, Index, IndexMut},
+    ops::{Index, IndexMut},
     str::FromStr,
 };
 
-#[derive(Debug)]
-pub enum ParseError {
-    InvalidInput,
-}
+mod grid3;
+mod parse;
+// `vector` defines `impl_vector_ops!`, which `Vector2i` shares.
+#[macro_use]
+mod vector;
+pub use grid3::*;
+pub use parse::*;
+pub use vector::*;
 
 pub const UP: Vector2i = Vector2i { x: 0, y: -1 };
 pub const UP_RIGHT: Vector2i = Vector2i { x: 1, y: -1 };
@@ -29,6 +33,7 @@ pub static EIGHTWAY_DIRS: [Vector2i; 8] = [
     UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT,
 ];
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum AdjacencyRule {
     Cardinals,
     EightWay,
@@ -51,14 +56,12 @@ impl FromStr for Vector2i {
         let mut it = s.split(",");
         let x = it
             .next()
-            .ok_or(ParseError::InvalidInput)?
-            .parse::<i64>()
-            .map_err(|_| ParseError::InvalidInput)?;
+            .ok_or_else(|| ParseError::missing(s, "an x coordinate"))?;
+        let x: i64 = parse_value(x, "an x coordinate")?;
         let y = it
             .next()
-            .ok_or(ParseError::InvalidInput)?
-            .parse::<i64>()
-            .map_err(|_| ParseError::InvalidInput)?;
+            .ok_or_else(|| ParseError::missing(s, "',' and a y coordinate"))?;
+        let y: i64 = parse_value(y, "a y coordinate")?;
 
         Ok(Vector2i { x, y })
     }
@@ -98,20 +101,62 @@ impl Vector2i {
             },
         }
     }
-}
 
-impl ops::Add<&Vector2i> for Vector2i {
-    type Output = Self;
+    /// Returns the sign of each component: -1, 0 or 1.  This turns a difference between two
+    /// positions into a single step towards the first one.
+    pub fn signum(self) -> Self {
+        Vector2i::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Returns the absolute value of each component.
+    pub fn abs(self) -> Self {
+        Vector2i::new(self.x.abs(), self.y.abs())
+    }
 
-    /// Adds the corresponding x and y components of the Vector2i together.
-    fn add(self, rhs: &Vector2i) -> Self {
-        Vector2i {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+    /// Rotates the Vector2i by 90° counter-clockwise, visually: `UP` becomes `LEFT`.
+    pub fn rotate_left(self) -> Self {
+        Vector2i::new(self.y, -self.x)
+    }
+
+    /// Rotates the Vector2i by 90° clockwise, visually: `UP` becomes `RIGHT`.
+    pub fn rotate_right(self) -> Self {
+        Vector2i::new(-self.y, self.x)
+    }
+
+    /// Returns the dot product of the two Vector2is.
+    pub fn dot(self, other: Vector2i) -> i64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the sum of the differences between each component of the two Vector2is.
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns the largest difference between a component of the two Vector2is.
+    pub fn chebyshev_distance(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Applies `f` to each component, for `impl_vector_ops!`.
+    fn map_components(self, f: impl Fn(i64) -> i64) -> Self {
+        Vector2i::new(f(self.x), f(self.y))
+    }
+
+    /// Combines each component with the same one of `other`, for `impl_vector_ops!`.
+    fn zip_components(self, other: Self, f: impl Fn(i64, i64) -> i64) -> Self {
+        Vector2i::new(f(self.x, other.x), f(self.y, other.y))
+    }
+}
+
+impl From<(i64, i64)> for Vector2i {
+    fn from((x, y): (i64, i64)) -> Self {
+        Vector2i { x, y }
     }
 }
 
+impl_vector_ops!(Vector2i);
+
 pub struct NeighborIterator {
     point: Vector2i,
     iter: core::slice::Iter<'static, Vector2i>,
@@ -129,12 +174,14 @@ impl Iterator for NeighborIterator {
     }
 }
 
+/// Same as `p1.chebyshev_distance(p2)`.
 pub fn chebyshev_distance(p1: &Vector2i, p2: &Vector2i) -> u64 {
-    p2.x.abs_diff(p1.x).max(p2.y.abs_diff(p1.y))
+    p1.chebyshev_distance(p2)
 }
 
+/// Same as `p1.manhattan_distance(p2)`.
 pub fn manhattan_distance(p1: &Vector2i, p2: &Vector2i) -> u64 {
-    p2.x.abs_diff(p1.x) + p2.y.abs_diff(p1.y)
+    p1.manhattan_distance(p2)
 }
 /// Represents a 2-dimensional, integral grid of values.
 ///
@@ -165,13 +212,17 @@ impl<T> IndexMut<usize> for Grid<T> {
 impl<T> Index<Vector2i> for Grid<T> {
     type Output = T;
 
+    /// Gets the value at the given position.  Positions outside the grid would silently alias
+    /// another cell, so debug builds panic on them; use `get` when a position may be outside.
     fn index(&self, pos: Vector2i) -> &Self::Output {
+        self.debug_assert_contains(&pos);
         &self.values[pos.to_index(self.width)]
     }
 }
 
 impl<T> IndexMut<Vector2i> for Grid<T> {
     fn index_mut(&mut self, pos: Vector2i) -> &mut Self::Output {
+        self.debug_assert_contains(&pos);
         &mut self.values[pos.to_index(self.width)]
     }
 }
@@ -225,6 +276,87 @@ impl<T> Grid<T> {
     pub fn positions(&self) -> impl Iterator<Item = Vector2i> + '_ {
         (0..self.values.len()).map(|i| Vector2i::new_from_index(i as u64, self.width() as u64))
     }
+
+    /// Returns the value at the given position, or `None` if the position is outside the grid.
+    pub fn get(&self, pos: Vector2i) -> Option<&T> {
+        self.contains(&pos)
+            .then(|| &self.values[pos.to_index(self.width)])
+    }
+
+    /// Returns the value at the given position mutably, or `None` if the position is outside the
+    /// grid.
+    pub fn get_mut(&mut self, pos: Vector2i) -> Option<&mut T> {
+        let width = self.width;
+        self.contains(&pos)
+            .then(|| &mut self.values[pos.to_index(width)])
+    }
+
+    /// Returns the value at the given position, wrapping around the edges: the grid repeats
+    /// endlessly in every direction, like the surface of a torus.
+    pub fn get_wrapped(&self, pos: Vector2i) -> &T {
+        let wrapped = Vector2i::new(
+            pos.x.rem_euclid(self.width() as i64),
+            pos.y.rem_euclid(self.height() as i64),
+        );
+        &self.values[wrapped.to_index(self.width)]
+    }
+
+    /// Iterates over the cells of row `y`, left to right, along with their positions.  Use `rev`
+    /// to scan from the right.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = (Vector2i, &T)> + Clone + '_ {
+        (0..self.width()).map(move |x| {
+            let pos = Vector2i::new(x as i64, y as i64);
+            (pos, &self[pos])
+        })
+    }
+
+    /// Iterates over the cells of column `x`, top to bottom, along with their positions.  Use
+    /// `rev` to scan from the bottom.
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = (Vector2i, &T)> + Clone + '_ {
+        (0..self.height()).map(move |y| {
+            let pos = Vector2i::new(x as i64, y as i64);
+            (pos, &self[pos])
+        })
+    }
+
+    /// Iterates over every row of the grid, top to bottom, as returned by `row`.
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vector2i, &T)> + Clone + '_> + '_
+    {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    /// Iterates over every column of the grid, left to right, as returned by `col`.
+    pub fn cols(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vector2i, &T)> + Clone + '_> + '_
+    {
+        (0..self.width()).map(|x| self.col(x))
+    }
+
+    /// Iterates over the cells in a straight line from `from`, stepping by `dir` until the edge of
+    /// the grid, along with their positions.  The cell at `from` itself is not included.
+    pub fn ray(&self, from: Vector2i, dir: Vector2i) -> impl Iterator<Item = (Vector2i, &T)> + '_ {
+        assert!(
+            dir != Vector2i::new(0, 0),
+            "a ray needs a non-zero direction"
+        );
+        std::iter::successors(Some(from + dir), move |&pos| Some(pos + dir))
+            .take_while(|pos| self.contains(pos))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    fn debug_assert_contains(&self, pos: &Vector2i) {
+        debug_assert!(
+            self.contains(pos),
+            "position ({}, {}) is outside the {}x{} grid",
+            pos.x,
+            pos.y,
+            self.width(),
+            self.height()
+        );
+    }
 }
 
 impl<T> Grid<T>
@@ -300,3 +432,87 @@ pub fn gcd(first: u64, second: u64) -> u64 {
         min = res;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_ops() {
+        let a = Vector2i::new(3, -2);
+        let b = Vector2i::from((1, 4));
+
+        assert_eq!(a + b, Vector2i::new(4, 2));
+        assert_eq!(a - b, Vector2i::new(2, -6));
+        assert_eq!(-a, Vector2i::new(-3, 2));
+        assert_eq!(a * 2, Vector2i::new(6, -4));
+        assert_eq!(a / 2, Vector2i::new(1, -1));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_grid_access() {
+        let mut grid = Grid::new((0..6).collect(), 3);
+
+        assert_eq!(grid.get(Vector2i::new(2, 1)), Some(&5));
+        assert_eq!(grid.get(Vector2i::new(3, 0)), None);
+        assert_eq!(grid.get(Vector2i::new(0, -1)), None);
+
+        *grid.get_mut(Vector2i::new(1, 0)).unwrap() = 10;
+        assert_eq!(grid[Vector2i::new(1, 0)], 10);
+        assert!(grid.get_mut(Vector2i::new(-1, 0)).is_none());
+
+        assert_eq!(grid.get_wrapped(Vector2i::new(3, 0)), &0);
+        assert_eq!(grid.get_wrapped(Vector2i::new(-1, -1)), &5);
+        assert_eq!(grid.get_wrapped(Vector2i::new(7, 4)), &10);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "position (3, 0) is outside the 3x2 grid")]
+    fn test_grid_index_out_of_bounds() {
+        let grid = Grid::new((0..6).collect::<Vec<u32>>(), 3);
+        let _ = grid[Vector2i::new(3, 0)];
+    }
+
+    #[test]
+    fn test_grid_lines() {
+        let grid = Grid::new((0..6).collect(), 3);
+        let values = |line: &mut dyn Iterator<Item = (Vector2i, &i32)>| -> Vec<i32> {
+            line.map(|(_, &value)| value).collect()
+        };
+
+        assert_eq!(values(&mut grid.row(1)), [3, 4, 5]);
+        assert_eq!(values(&mut grid.row(0).rev()), [2, 1, 0]);
+        assert_eq!(values(&mut grid.col(2)), [2, 5]);
+        assert_eq!(grid.col(1).last(), Some((Vector2i::new(1, 1), &4)));
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.cols().map(|col| col.count()).sum::<usize>(), 6);
+
+        assert_eq!(values(&mut grid.ray(Vector2i::new(0, 0), RIGHT)), [1, 2]);
+        assert_eq!(values(&mut grid.ray(Vector2i::new(0, 0), DOWN_RIGHT)), [4]);
+        assert_eq!(values(&mut grid.ray(Vector2i::new(2, 1), UP)), [2]);
+        assert_eq!(grid.ray(Vector2i::new(0, 0), LEFT).count(), 0);
+    }
+
+    #[test]
+    fn test_vector_methods() {
+        let a = Vector2i::new(-5, 3);
+
+        assert_eq!(a.signum(), Vector2i::new(-1, 1));
+        assert_eq!(a.abs(), Vector2i::new(5, 3));
+        assert_eq!(a.dot(Vector2i::new(2, 4)), 2);
+        assert_eq!(a.manhattan_distance(&UP), 9);
+        assert_eq!(a.chebyshev_distance(&UP), 5);
+        assert_eq!(manhattan_distance(&a, &UP), a.manhattan_distance(&UP));
+
+        assert_eq!(UP.rotate_right(), RIGHT);
+        assert_eq!(UP.rotate_left(), LEFT);
+        assert_eq!(a.rotate_left().rotate_right(), a);
+        assert_eq!(a.rotate_right().rotate_right(), -a);
+    }
+}
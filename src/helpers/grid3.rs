@@ -0,0 +1,254 @@
+/*
+ * A 3-dimensional grid, re-exported from `helpers`.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use super::{AdjacencyRule, Vector3i};
+
+/// Represents a 3-dimensional, integral grid of values, e.g. voxels.
+///
+/// Like `Grid`, it stores the backing values in a vector of the appropriate size, and allows you to
+/// access and/or iterate over them both by position and index.
+#[derive(Debug)]
+pub struct Grid3<T> {
+    /// The values in the grid.
+    values: Vec<T>,
+    /// The width of the grid, along x.
+    width: usize,
+    /// The height of the grid, along y.
+    height: usize,
+}
+
+impl<T> Index<usize> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
+
+impl<T> Index<Vector3i> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector3i) -> &Self::Output {
+        self.debug_assert_contains(&pos);
+        &self.values[pos.to_index(self.size())]
+    }
+}
+
+impl<T> IndexMut<Vector3i> for Grid3<T> {
+    fn index_mut(&mut self, pos: Vector3i) -> &mut Self::Output {
+        self.debug_assert_contains(&pos);
+        let index = pos.to_index(self.size());
+        &mut self.values[index]
+    }
+}
+
+impl<T> Grid3<T> {
+    /// Creates a new grid based on the values in the given vector, and the width and height
+    /// specified.
+    ///
+    /// The values vector should have indices which corresponds to positions as defined in the
+    /// `VectorN::to_index` function.  The vector must have exactly width * height * depth values
+    /// (the depth of the grid is inferred from the grid size).
+    pub fn new(values: Vec<T>, width: usize, height: usize) -> Self {
+        assert!(values.len().is_multiple_of(width * height));
+        Self {
+            values,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the width of the grid, along x.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid, along y.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the depth of the grid, along z.
+    pub fn depth(&self) -> usize {
+        self.values.len() / (self.width * self.height)
+    }
+
+    /// Returns the width, height and depth of the grid, as used by `VectorN::to_index`.
+    pub fn size(&self) -> [usize; 3] {
+        [self.width(), self.height(), self.depth()]
+    }
+
+    /// Returns the total number of cells in the grid (will always be width() * height() * depth())
+    pub fn num_cells(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.values.iter_mut()
+    }
+
+    /// Returns whether or not the given position is contained within this grid.
+    pub fn contains(&self, pos: &Vector3i) -> bool {
+        pos.coords
+            .iter()
+            .zip(self.size())
+            .all(|(&coord, length)| coord >= 0 && (coord as usize) < length)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Vector3i> + '_ {
+        (0..self.values.len()).map(|i| Vector3i::new_from_index(i, self.size()))
+    }
+
+    /// Returns the neighbors of the given position which are within the grid.
+    pub fn neighbors(
+        &self,
+        pos: Vector3i,
+        adjacency_rule: AdjacencyRule,
+    ) -> impl Iterator<Item = Vector3i> + '_ {
+        pos.neighbors(adjacency_rule)
+            .filter(|neighbor| self.contains(neighbor))
+    }
+
+    /// Finds every position which can be reached from `start` by moving between neighbors whose
+    /// values `can_enter` accepts, e.g. the air around a droplet.  Returns a grid which is `true`
+    /// for the reached positions; the start is reached if it is in the grid and accepted.
+    pub fn flood_fill(
+        &self,
+        start: Vector3i,
+        adjacency_rule: AdjacencyRule,
+        can_enter: impl Fn(&T) -> bool,
+    ) -> Grid3<bool> {
+        let mut reached = Grid3::new_empty(self.width(), self.height(), self.depth(), false);
+        if !self.contains(&start) || !can_enter(&self[start]) {
+            return reached;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        reached[start] = true;
+        while let Some(pos) = queue.pop_front() {
+            for neighbor in self.neighbors(pos, adjacency_rule) {
+                if !reached[neighbor] && can_enter(&self[neighbor]) {
+                    reached[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        reached
+    }
+
+    fn debug_assert_contains(&self, pos: &Vector3i) {
+        debug_assert!(
+            self.contains(pos),
+            "position ({}) is outside the {}x{}x{} grid",
+            pos,
+            self.width(),
+            self.height(),
+            self.depth()
+        );
+    }
+}
+
+impl<T> Grid3<T>
+where
+    T: std::clone::Clone,
+{
+    /// Creates a new grid of the given size, with every cell initialized to the given default value.
+    pub fn new_empty(width: usize, height: usize, depth: usize, default_value: T) -> Self {
+        Self {
+            values: vec![default_value; width * height * depth],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Display for Grid3<T>
+where
+    T: Display,
+{
+    /// Prints each layer of the grid like `Grid` does, z = 0 first, with a blank line in between.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for z in 0..self.depth() {
+            if z > 0 {
+                writeln!(f)?;
+            }
+            for y in 0..self.height() {
+                for x in 0..self.width() {
+                    write!(f, "{}", self[Vector3i::new(x as i64, y as i64, z as i64)])?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid3() {
+        let grid = Grid3::new((0..24).collect(), 4, 3);
+        assert_eq!(grid.size(), [4, 3, 2]);
+        assert_eq!(grid.num_cells(), 24);
+        assert_eq!(grid[Vector3i::new(1, 2, 1)], 1 + 4 * (2 + 3));
+        assert_eq!(grid[21], 21);
+
+        assert!(grid.contains(&Vector3i::new(3, 2, 1)));
+        assert!(!grid.contains(&Vector3i::new(3, 2, 2)));
+        assert!(!grid.contains(&Vector3i::new(-1, 0, 0)));
+
+        let positions: Vec<_> = grid.positions().collect();
+        assert_eq!(positions.len(), 24);
+        assert!(positions
+            .iter()
+            .all(|&pos| grid[pos] == pos.to_index(grid.size())));
+
+        assert_eq!(
+            grid.neighbors(Vector3i::ZERO, AdjacencyRule::Cardinals)
+                .count(),
+            3
+        );
+        assert_eq!(
+            grid.neighbors(Vector3i::ZERO, AdjacencyRule::EightWay)
+                .count(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        // A hollow 3x3x3 cube with a solid wall, in a 5x5x5 grid of air.
+        let mut grid = Grid3::new_empty(5, 5, 5, false);
+        for pos in grid.positions().collect::<Vec<_>>() {
+            let inside = pos.coords.iter().all(|&c| (1..=3).contains(&c));
+            let center = pos == Vector3i::new(2, 2, 2);
+            grid[pos] = inside && !center;
+        }
+
+        let outside = grid.flood_fill(Vector3i::ZERO, AdjacencyRule::Cardinals, |&solid| !solid);
+        assert_eq!(outside.iter().filter(|&&reached| reached).count(), 125 - 27);
+        assert!(!outside[Vector3i::new(2, 2, 2)]);
+
+        let solid = grid.flood_fill(Vector3i::ZERO, AdjacencyRule::Cardinals, |&solid| solid);
+        assert!(solid.iter().all(|&reached| !reached));
+    }
+}
@@ -0,0 +1,454 @@
+/*
+ * Helpers for parsing puzzle input, re-exported from `helpers`.
+ */
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::Grid;
+
+/// An error found while parsing puzzle input.
+///
+/// Errors are created from the slice of the input which could not be parsed.  Once the error
+/// reaches code which has the whole input, `locate` works out the line and column of that slice,
+/// so `FromStr` implementations which only see a single line don't need to know where it
+/// is.
+#[derive(Clone)]
+pub struct ParseError {
+    /// The 1-based number of the line the error is on, once located.
+    pub line: Option<usize>,
+    /// The 1-based number of the character within the line the snippet starts at, once located.
+    pub column: Option<usize>,
+    /// The byte offset of the snippet from the start of the input, once located.
+    pub offset: Option<usize>,
+    /// The text which could not be parsed.  Empty if the text ended too early.
+    pub snippet: String,
+    /// What was expected instead, e.g. "'Valve '" or "a number".
+    pub expected: String,
+    /// Address of the snippet, used to find it within the input.
+    address: usize,
+}
+
+impl ParseError {
+    /// Creates an error for the given slice of the text being parsed.
+    pub fn new(snippet: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            offset: None,
+            snippet: snippet.to_string(),
+            expected: expected.into(),
+            address: snippet.as_ptr() as usize,
+        }
+    }
+
+    /// Creates an error for text which ended before the expected value.
+    pub fn missing(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(&text[text.len()..], expected)
+    }
+
+    /// Finds the line, column and offset of the error within the given input.  The error is left as is
+    /// if its snippet isn't a slice of the input.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if (start..=start + input.len()).contains(&self.address) {
+            let offset = self.address - start;
+            let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+            self.offset = Some(offset);
+            self.line = Some(input[..offset].matches('\n').count() + 1);
+            self.column = Some(input[line_start..offset].chars().count() + 1);
+        }
+
+        self
+    }
+}
+
+impl std::fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParseError")
+            .field("line", &self.line)
+            .field("column", &self.column)
+            .field("offset", &self.offset)
+            .field("snippet", &self.snippet)
+            .field("expected", &self.expected)
+            .finish()
+    }
+}
+
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+            && self.column == other.column
+            && self.offset == other.offset
+            && self.snippet == other.snippet
+            && self.expected == other.expected
+    }
+}
+
+impl Eq for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "{}:{}: ", line, column)?;
+        }
+
+        if self.snippet.is_empty() {
+            write!(f, "expected {}, found the end of the text", self.expected)
+        } else {
+            write!(f, "expected {}, found {:?}", self.expected, self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Returns the rest of the text after the given prefix, or an error expecting the prefix.
+pub fn expect_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("'{}'", prefix)))
+}
+
+/// Parses a value with `FromStr`, or returns an error expecting the given description.
+pub fn parse_value<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, expected))
+}
+
+/// Parses every line of the input, locating any error within the whole input.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.parse::<T>().map_err(|e| e.locate(input)))
+        .collect()
+}
+
+/// Matches the text against a pattern in which each `{}` stands for a value, and returns the text
+/// of each value.  A value ends at the first occurrence of the pattern text which follows it, so
+/// patterns can't have two `{}` next to each other.
+///
+/// ```
+/// use advent_of_code::helpers::scan;
+///
+/// let values = scan("Sensor at x=2, y=-18: closest", "Sensor at x={}, y={}: closest").unwrap();
+/// assert_eq!(values, ["2", "-18"]);
+/// ```
+pub fn scan<'a>(s: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split("{}");
+    let mut rest = expect_prefix(s, literals.next().unwrap_or_default())?;
+    let literals: Vec<_> = literals.collect();
+
+    let mut values = Vec::with_capacity(literals.len());
+    for (i, literal) in literals.iter().enumerate() {
+        let end = if i + 1 == literals.len() {
+            rest.strip_suffix(literal).map(str::len)
+        } else {
+            assert!(
+                !literal.is_empty(),
+                "pattern {:?} has two values next to each other",
+                pattern
+            );
+            rest.find(literal)
+        };
+        let end = end.ok_or_else(|| ParseError::new(rest, format!("'{}'", literal)))?;
+
+        values.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    if literals.is_empty() && !rest.is_empty() {
+        return Err(ParseError::new(rest, "the end of the line"));
+    }
+
+    Ok(values)
+}
+
+/// Like `scan`, but parses each value as the same type.  Panics if the pattern doesn't have `N`
+/// values.
+pub fn scan_values<T: FromStr, const N: usize>(
+    s: &str,
+    pattern: &str,
+) -> Result<[T; N], ParseError> {
+    let values = scan(s, pattern)?
+        .into_iter()
+        .map(|v| parse_value(v, "a value"))
+        .collect::<Result<Vec<T>, _>>()?;
+
+    match values.try_into() {
+        Ok(values) => Ok(values),
+        Err(_) => panic!("pattern {:?} doesn't have {} values", pattern, N),
+    }
+}
+
+/// Parses the signed integer at the start of the text, and returns it with the rest of the text.
+pub fn scan_int<T: FromStr>(s: &str) -> Result<(T, &str), ParseError> {
+    let sign = s.starts_with(['-', '+']) as usize;
+    let end = s[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(s.len(), |i| i + sign);
+
+    if end == sign {
+        return Err(ParseError::new(s, "an integer"));
+    }
+
+    Ok((parse_value(&s[..end], "an integer")?, &s[end..]))
+}
+
+/// Returns the text of every integer in `s`, with its sign.  A '-' or '+' right after a digit is a
+/// separator rather than a sign, so "2-4" holds 2 and 4.
+fn integer_tokens(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let start = pos;
+            let signed = matches!(bytes[start], b'-' | b'+')
+                && (start == 0 || !bytes[start - 1].is_ascii_digit());
+            let digits = start + signed as usize;
+            let end = bytes[digits..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .map_or(bytes.len(), |i| digits + i);
+
+            if end > digits {
+                pos = end;
+                return Some(&s[start..end]);
+            }
+            pos += 1;
+        }
+        None
+    })
+}
+
+/// Parses every integer in the text, skipping anything in between, e.g. the coordinates in
+/// "Sensor at x=2, y=-18".
+///
+/// ```
+/// use advent_of_code::helpers::integers;
+///
+/// let values: Vec<i64> = integers("Sensor at x=2, y=-18").collect::<Result<_, _>>().unwrap();
+/// assert_eq!(values, [2, -18]);
+/// ```
+pub fn integers<T: FromStr>(s: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    integer_tokens(s).map(|token| parse_value(token, "an integer"))
+}
+
+/// Like `integers`, but expects exactly `N` integers in the text.
+pub fn integers_n<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    let mut tokens = integer_tokens(s);
+    let values = tokens
+        .by_ref()
+        .take(N)
+        .map(|token| parse_value(token, "an integer"))
+        .collect::<Result<Vec<T>, _>>()?;
+
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::new(
+            extra,
+            format!("the end of the line after {} integers", N),
+        ));
+    }
+
+    values
+        .try_into()
+        .map_err(|_| ParseError::missing(s, format!("{} integers", N)))
+}
+
+/// Splits the input into blocks of lines which are separated by blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_end_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Parses every block of the input (see `blocks`), locating any error within the whole input.
+pub fn parse_blocks<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    blocks(input)
+        .map(|b| b.parse::<T>().map_err(|e| e.locate(input)))
+        .collect()
+}
+
+/// Parses a list of values separated by `separator`, e.g. "79, 98" with ", ".  `expected`
+/// describes a single value.
+pub fn parse_list<T: FromStr>(
+    s: &str,
+    separator: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    s.split(separator)
+        .map(|v| parse_value(v, expected))
+        .collect()
+}
+
+/// Parses a grid with one character per cell, e.g. a map of '#' and '.'.  `cell` turns a character
+/// into the value of its cell, or returns `None` if it isn't valid, which is reported using
+/// `expected`.  Every row must have the same width.
+pub fn parse_grid<T>(
+    input: &str,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Grid<T>, ParseError> {
+    let mut values = vec![];
+    let mut width = None;
+
+    for line in input.lines() {
+        let mut line_width = 0;
+        for (i, c) in line.char_indices() {
+            let value = cell(c).ok_or_else(|| {
+                ParseError::new(&line[i..i + c.len_utf8()], expected).locate(input)
+            })?;
+            values.push(value);
+            line_width += 1;
+        }
+
+        match width {
+            None => width = Some(line_width),
+            Some(width) if width != line_width => {
+                let expected = format!("a row of {} cells", width);
+                return Err(ParseError::new(line, expected).locate(input));
+            }
+            Some(_) => {}
+        }
+    }
+
+    match width {
+        Some(width) if width > 0 => Ok(Grid::new(values, width)),
+        _ => Err(ParseError::missing(input, "a row of cells")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Vector2i;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1,2\n3,x\n5,6";
+        let err = parse_lines::<Vector2i>(input).unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(3));
+        assert_eq!(err.offset, Some(6));
+        assert_eq!(err.snippet, "x");
+        assert_eq!(err.to_string(), "2:3: expected a y coordinate, found \"x\"");
+
+        let err = parse_lines::<Vector2i>("1,2\n3").unwrap_err();
+        assert_eq!((err.line, err.offset), (Some(2), Some(5)));
+        assert_eq!(
+            err.to_string(),
+            "2:2: expected ',' and a y coordinate, found the end of the text"
+        );
+
+        let input = "Valve AA has flow rate=0\nValv BB has flow rate=13";
+        let line = input.lines().nth(1).unwrap();
+        let err = expect_prefix(line, "Valve ").unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected 'Valve ', found \"Valv BB has flow rate=13\""
+        );
+
+        // Columns count characters, not bytes.
+        let input = "é\nää x";
+        let err = expect_prefix(&input[8..], "y").unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.offset),
+            (Some(2), Some(4), Some(8))
+        );
+
+        // Snippets which aren't part of the input can't be located.
+        let err = ParseError::new("x", "a number").locate(input);
+        assert_eq!((err.line, err.column, err.offset), (None, None, None));
+        assert_eq!(err.to_string(), "expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn test_scan() {
+        let line = "Valve AA has flow rate=13; tunnels lead to valves DD, II";
+        let values = scan(line, "Valve {} has flow rate={}; {}").unwrap();
+        assert_eq!(values, ["AA", "13", "tunnels lead to valves DD, II"]);
+
+        let err = scan(line, "Valve {} has flow rate={}: {}").unwrap_err();
+        assert_eq!(err.expected, "': '");
+        assert_eq!(err.snippet, "13; tunnels lead to valves DD, II");
+
+        let values: [i64; 4] = scan_values(
+            "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15",
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        )
+        .unwrap();
+        assert_eq!(values, [2, -18, -2, 15]);
+
+        assert_eq!(scan("noop", "noop").unwrap(), Vec::<&str>::new());
+        assert!(scan("noop 1", "noop").is_err());
+    }
+
+    #[test]
+    fn test_scan_int() {
+        assert_eq!(scan_int::<i64>("-18, y=5"), Ok((-18, ", y=5")));
+        assert_eq!(scan_int::<u32>("+7"), Ok((7, "")));
+        assert!(scan_int::<i64>("x=1").is_err());
+        assert!(scan_int::<i64>("-").is_err());
+        assert!(scan_int::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=+15";
+        let values = integers::<i64>(line).collect::<Result<Vec<_>, _>>();
+        assert_eq!(values, Ok(vec![2, -18, -2, 15]));
+        assert_eq!(integers_n::<u32, 4>("2-4,6-8"), Ok([2, 4, 6, 8]));
+        assert_eq!(integers_n::<usize, 3>("move 1 from 2 to 1"), Ok([1, 2, 1]));
+        assert_eq!(integers::<i64>("noop").count(), 0);
+
+        let err = integers_n::<u32, 4>("2-4,6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected 4 integers, found the end of the text"
+        );
+        let err = integers_n::<u32, 2>("2-4,6-8").unwrap_err();
+        assert_eq!(err.snippet, "6");
+
+        let input = "x=1\nx=-3";
+        let line = input.lines().nth(1).unwrap();
+        let err = integers_n::<u32, 1>(line).unwrap_err().locate(input);
+        assert_eq!((err.line, err.offset), (Some(2), Some(6)));
+        assert_eq!(err.snippet, "-3");
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_grid("#.#\n..#", Some, "a cell").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vector2i::new(2, 1)], '#');
+
+        let cell = |c: char| c.to_digit(10);
+        let err = parse_grid("123\n4x6", cell, "a digit").unwrap_err();
+        assert_eq!(
+            (err.line, err.offset, err.snippet.as_str()),
+            (Some(2), Some(5), "x")
+        );
+        let err = parse_grid("123\n45", cell, "a digit").unwrap_err();
+        assert_eq!(err.expected, "a row of 3 cells");
+        assert!(parse_grid("", cell, "a digit").is_err());
+    }
+
+    #[test]
+    fn test_blocks_and_lists() {
+        let input = "1,2\n3,4\n\n5,6\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), ["1,2\n3,4", "5,6"]);
+
+        let lists = blocks(input)
+            .map(|b| parse_list::<Vector2i>(b, "\n", "a position"))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(lists[1], [Vector2i { x: 5, y: 6 }]);
+
+        assert_eq!(
+            parse_list("79, 98", ", ", "a worry level"),
+            Ok(vec![79, 98])
+        );
+        let err = parse_list::<u32>("79, x", ", ", "a worry level").unwrap_err();
+        assert_eq!(err.snippet, "x");
+    }
+}
@@ -0,0 +1,397 @@
+/*
+ * Vectors with any number of dimensions, re-exported from `helpers`.
+ */
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use super::{parse_value, AdjacencyRule, ParseError, Vector2i};
+
+/// Represents a vector with `D` integer coordinates, e.g. a position in a 3d or 4d grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VectorN<const D: usize> {
+    /// The coordinates, x first.
+    pub coords: [i64; D],
+}
+
+/// Represents a 3d vector with integer coordinates.
+pub type Vector3i = VectorN<3>;
+
+impl<const D: usize> VectorN<D> {
+    /// The vector with every coordinate zero.
+    pub const ZERO: Self = VectorN { coords: [0; D] };
+
+    /// Creates a new vector which represents the index value given, assuming it is encoded with
+    /// the given size, one length per dimension.
+    ///
+    /// Index is defined as x + width * (y + height * (z + ...)).
+    pub fn new_from_index(mut index: usize, size: [usize; D]) -> Self {
+        let mut coords = [0; D];
+        for (coord, length) in coords.iter_mut().zip(size) {
+            *coord = (index % length) as i64;
+            index /= length;
+        }
+        VectorN { coords }
+    }
+
+    /// Gets the "index" value associated with the given position, in a grid of the given size.
+    ///
+    /// Indexes are calculated as x + width * (y + height * (z + ...)), the inverse of
+    /// `new_from_index`.  This is useful for storing a grid of vectors in a 1-dimensional array.
+    pub fn to_index(&self, size: [usize; D]) -> usize {
+        self.coords
+            .iter()
+            .zip(size)
+            .rev()
+            .fold(0, |index, (&coord, length)| index * length + coord as usize)
+    }
+
+    /// Returns the neighboring positions.  `AdjacencyRule::Cardinals` gives the 2 * D positions
+    /// which differ in a single coordinate, e.g. the 6 faces of a cube, and
+    /// `AdjacencyRule::EightWay` gives all 3^D - 1 surrounding positions, e.g. 26 in 3d.
+    pub fn neighbors(self, adjacency_rule: AdjacencyRule) -> impl Iterator<Item = Self> {
+        let max_changes = match adjacency_rule {
+            AdjacencyRule::Cardinals => 1,
+            AdjacencyRule::EightWay => D,
+        };
+
+        (0..3_usize.pow(D as u32)).filter_map(move |mut n| {
+            // Every digit of n in base 3 picks an offset of -1, 0 or 1 for one coordinate.
+            let mut offset = [0; D];
+            for coord in offset.iter_mut() {
+                *coord = (n % 3) as i64 - 1;
+                n /= 3;
+            }
+
+            let changes = offset.iter().filter(|&&coord| coord != 0).count();
+            (1..=max_changes)
+                .contains(&changes)
+                .then(|| self + VectorN { coords: offset })
+        })
+    }
+
+    /// Returns the sign of each coordinate: -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        VectorN {
+            coords: self.coords.map(i64::signum),
+        }
+    }
+
+    /// Returns the absolute value of each coordinate.
+    pub fn abs(self) -> Self {
+        VectorN {
+            coords: self.coords.map(i64::abs),
+        }
+    }
+
+    /// Returns the dot product of the two vectors.
+    pub fn dot(self, other: Self) -> i64 {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// Returns the sum of the differences between each coordinate of the two vectors.
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(a, b)| a.abs_diff(b))
+            .sum()
+    }
+
+    /// Returns the largest difference between a coordinate of the two vectors.
+    pub fn chebyshev_distance(&self, other: &Self) -> u64 {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(a, b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Applies `f` to each coordinate, for `impl_vector_ops!`.
+    fn map_components(self, f: impl Fn(i64) -> i64) -> Self {
+        VectorN {
+            coords: self.coords.map(f),
+        }
+    }
+
+    /// Combines each coordinate with the same one of `other`, for `impl_vector_ops!`.
+    fn zip_components(mut self, other: Self, f: impl Fn(i64, i64) -> i64) -> Self {
+        for (a, b) in self.coords.iter_mut().zip(other.coords) {
+            *a = f(*a, b);
+        }
+        self
+    }
+}
+
+impl Vector3i {
+    /// Creates a new Vector3i.
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        VectorN { coords: [x, y, z] }
+    }
+
+    /// The x-value of the coordinate.
+    pub fn x(&self) -> i64 {
+        self.coords[0]
+    }
+
+    /// The y-value of the coordinate.
+    pub fn y(&self) -> i64 {
+        self.coords[1]
+    }
+
+    /// The z-value of the coordinate.
+    pub fn z(&self) -> i64 {
+        self.coords[2]
+    }
+}
+
+impl<const D: usize> Default for VectorN<D> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const D: usize> From<[i64; D]> for VectorN<D> {
+    fn from(coords: [i64; D]) -> Self {
+        VectorN { coords }
+    }
+}
+
+impl From<(i64, i64, i64)> for Vector3i {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Vector3i::new(x, y, z)
+    }
+}
+
+impl From<Vector2i> for VectorN<2> {
+    fn from(v: Vector2i) -> Self {
+        VectorN { coords: [v.x, v.y] }
+    }
+}
+
+impl From<VectorN<2>> for Vector2i {
+    fn from(v: VectorN<2>) -> Self {
+        Vector2i::new(v.coords[0], v.coords[1])
+    }
+}
+
+impl<const D: usize> Index<usize> for VectorN<D> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.coords[axis]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for VectorN<D> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.coords[axis]
+    }
+}
+
+impl<const D: usize> FromStr for VectorN<D> {
+    type Err = ParseError;
+
+    /// Reads in a vector from a string of D comma-separated coordinates, e.g. "x,y,z"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.split(',');
+        let mut coords = [0; D];
+        for (axis, coord) in coords.iter_mut().enumerate() {
+            let value = it.next().ok_or_else(|| match axis {
+                0 => ParseError::missing(s, "a coordinate"),
+                _ => ParseError::missing(s, "',' and a coordinate"),
+            })?;
+            *coord = parse_value(value, "a coordinate")?;
+        }
+
+        if let Some(extra) = it.next() {
+            return Err(ParseError::new(
+                extra,
+                format!("the end of the vector after {} coordinates", D),
+            ));
+        }
+
+        Ok(VectorN { coords })
+    }
+}
+
+impl<const D: usize> Display for VectorN<D> {
+    /// Formats the vector the way `FromStr` reads it, e.g. "1,2,3".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (axis, coord) in self.coords.iter().enumerate() {
+            if axis > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", coord)?;
+        }
+        Ok(())
+    }
+}
+
+/// Implements the component-wise operators of a vector type for owned and borrowed vectors: `+`
+/// and `-` with their assigning forms, scaling by an i64 with `*` and `/`, and negation.  The type
+/// needs `map_components` and `zip_components` methods.  Types generic over their number of
+/// dimensions name the const parameter after the type, e.g. `impl_vector_ops!(VectorN<D>, const D)`.
+macro_rules! impl_vector_ops {
+    ($vector:ty $(, const $d:ident)?) => {
+        impl_vector_ops!(@op [$(const $d: usize)?] $vector, Add, add, AddAssign, add_assign);
+        impl_vector_ops!(@op [$(const $d: usize)?] $vector, Sub, sub, SubAssign, sub_assign);
+        impl_vector_ops!(@scale [$(const $d: usize)?] $vector, Mul, mul);
+        impl_vector_ops!(@scale [$(const $d: usize)?] $vector, Div, div);
+
+        impl<$(const $d: usize)?> std::ops::Neg for $vector {
+            type Output = $vector;
+
+            fn neg(self) -> $vector {
+                self.map_components(|a| -a)
+            }
+        }
+
+        impl<$(const $d: usize)?> std::ops::Neg for &$vector {
+            type Output = $vector;
+
+            fn neg(self) -> $vector {
+                -*self
+            }
+        }
+    };
+    (@op [$($generics:tt)*] $vector:ty, $op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl<$($generics)*> std::ops::$op<$vector> for $vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: $vector) -> $vector {
+                self.zip_components(rhs, std::ops::$op::$method)
+            }
+        }
+
+        impl<$($generics)*> std::ops::$op<&$vector> for $vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: &$vector) -> $vector {
+                std::ops::$op::$method(self, *rhs)
+            }
+        }
+
+        impl<$($generics)*> std::ops::$op<$vector> for &$vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: $vector) -> $vector {
+                std::ops::$op::$method(*self, rhs)
+            }
+        }
+
+        impl<$($generics)*> std::ops::$op<&$vector> for &$vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: &$vector) -> $vector {
+                std::ops::$op::$method(*self, *rhs)
+            }
+        }
+
+        impl<$($generics)*> std::ops::$assign_op<$vector> for $vector {
+            fn $assign_method(&mut self, rhs: $vector) {
+                *self = std::ops::$op::$method(*self, rhs);
+            }
+        }
+
+        impl<$($generics)*> std::ops::$assign_op<&$vector> for $vector {
+            fn $assign_method(&mut self, rhs: &$vector) {
+                *self = std::ops::$op::$method(*self, *rhs);
+            }
+        }
+    };
+    (@scale [$($generics:tt)*] $vector:ty, $op:ident, $method:ident) => {
+        impl<$($generics)*> std::ops::$op<i64> for $vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: i64) -> $vector {
+                self.map_components(|a| std::ops::$op::$method(a, rhs))
+            }
+        }
+
+        impl<$($generics)*> std::ops::$op<i64> for &$vector {
+            type Output = $vector;
+
+            fn $method(self, rhs: i64) -> $vector {
+                std::ops::$op::$method(*self, rhs)
+            }
+        }
+    };
+}
+
+impl_vector_ops!(VectorN<D>, const D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1,-2,3".parse(), Ok(Vector3i::new(1, -2, 3)));
+        assert_eq!(
+            "1,2,3,4".parse(),
+            Ok(VectorN {
+                coords: [1, 2, 3, 4]
+            })
+        );
+        assert_eq!(Vector3i::new(1, -2, 3).to_string(), "1,-2,3");
+
+        let err = "1,2".parse::<Vector3i>().unwrap_err();
+        assert_eq!(err.expected, "',' and a coordinate");
+        let err = "1,2,3,4".parse::<Vector3i>().unwrap_err();
+        assert_eq!(err.snippet, "4");
+        let err = "1,x,3".parse::<Vector3i>().unwrap_err();
+        assert_eq!(err.snippet, "x");
+    }
+
+    #[test]
+    fn test_ops_and_distances() {
+        let a = Vector3i::new(1, 2, 3);
+        let b = Vector3i::from((4, 0, -3));
+
+        assert_eq!(a + b, Vector3i::new(5, 2, 0));
+        assert_eq!(b - a, Vector3i::new(3, -2, -6));
+        assert_eq!(-a * 2, Vector3i::new(-2, -4, -6));
+        assert_eq!((b - a).signum(), Vector3i::new(1, -1, -1));
+        assert_eq!(a.dot(b), -5);
+        assert_eq!(a.manhattan_distance(&b), 11);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Vector3i::ZERO;
+        let faces: Vec<_> = origin.neighbors(AdjacencyRule::Cardinals).collect();
+        assert_eq!(faces.len(), 6);
+        assert!(faces.iter().all(|n| n.manhattan_distance(&origin) == 1));
+
+        let around: Vec<_> = origin.neighbors(AdjacencyRule::EightWay).collect();
+        assert_eq!(around.len(), 26);
+        assert!(around.iter().all(|n| n.chebyshev_distance(&origin) == 1));
+
+        assert_eq!(
+            VectorN::<4>::ZERO
+                .neighbors(AdjacencyRule::EightWay)
+                .count(),
+            80
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let size = [4, 3, 2];
+        let v = Vector3i::new(3, 1, 1);
+        assert_eq!(v.to_index(size), 3 + 4 * (1 + 3));
+        assert_eq!(Vector3i::new_from_index(v.to_index(size), size), v);
+        assert_eq!(
+            VectorN::<2>::from(Vector2i::new(2, 1)).to_index([5, 5]),
+            Vector2i::new(2, 1).to_index(5)
+        );
+    }
+}
@@ -0,0 +1,249 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::results::PartResult;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The prefix of the line which starts each run in the history file, followed by the run's Unix
+/// timestamp.
+const RUN_PREFIX: &str = "# run ";
+
+/// Returns the path of the history file for the current build profile.  Debug and release runs
+/// are recorded separately, as their timings can't be compared.
+pub fn history_path() -> PathBuf {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("history")
+        .join(format!("{}.jsonl", profile))
+}
+
+/// One recorded run of the runner: the results of every part which ran.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    /// When the run finished, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub results: Vec<PartResult>,
+}
+
+impl Run {
+    /// Creates a run which finished now.
+    pub fn now(results: Vec<PartResult>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Run { timestamp, results }
+    }
+
+    fn elapsed(&self, year: i16, day: u8, part: u8) -> Option<Duration> {
+        self.results
+            .iter()
+            .find(|r| r.is_solved() && (r.year, r.day, r.part) == (year, day, part))
+            .map(|r| r.elapsed)
+    }
+}
+
+/// Every run recorded in a history file, oldest first.  The file holds a `# run <timestamp>`
+/// line for each run, followed by one line of JSON per part as printed by `--json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Loads the history at the given path.  A missing file is an empty history, and lines which
+    /// can't be read are skipped.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(History::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut runs: Vec<Run> = Vec::new();
+        for line in contents.lines() {
+            if let Some(timestamp) = line.strip_prefix(RUN_PREFIX) {
+                runs.push(Run {
+                    timestamp: timestamp.trim().parse().unwrap_or(0),
+                    results: Vec::new(),
+                });
+            } else if let (Some(run), Some(result)) = (runs.last_mut(), PartResult::from_json(line))
+            {
+                run.results.push(result);
+            }
+        }
+        History { runs }
+    }
+
+    /// Appends a run to the history file at the given path, creating it if needed.
+    pub fn append(path: &Path, run: &Run) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut contents = format!("{}{}\n", RUN_PREFIX, run.timestamp);
+        for result in &run.results {
+            contents.push_str(&result.to_json());
+            contents.push('\n');
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(contents.as_bytes())
+    }
+
+    /// Returns the time the given baseline recorded for a part, if it was solved.
+    fn baseline(&self, baseline: Baseline, year: i16, day: u8, part: u8) -> Option<Duration> {
+        match baseline {
+            Baseline::Previous => self
+                .runs
+                .iter()
+                .rev()
+                .find_map(|run| run.elapsed(year, day, part)),
+            Baseline::Best => self
+                .runs
+                .iter()
+                .filter_map(|run| run.elapsed(year, day, part))
+                .min(),
+        }
+    }
+
+    /// Compares the given results against the baseline and returns the solved parts which got
+    /// slower by more than `threshold` percent.
+    pub fn regressions(
+        &self,
+        results: &[PartResult],
+        baseline: Baseline,
+        threshold: f64,
+    ) -> Vec<Slowdown> {
+        results
+            .iter()
+            .filter(|result| result.is_solved())
+            .filter_map(|result| {
+                let before = self.baseline(baseline, result.year, result.day, result.part)?;
+                let slowdown = Slowdown {
+                    year: result.year,
+                    day: result.day,
+                    part: result.part,
+                    before,
+                    after: result.elapsed,
+                };
+                (slowdown.percent() > threshold).then_some(slowdown)
+            })
+            .collect()
+    }
+}
+
+/// Which recorded time a run is compared against.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recent run which solved the part.
+    #[default]
+    Previous,
+    /// The fastest recorded time of the part.
+    Best,
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "previous" => Ok(Baseline::Previous),
+            "best" => Ok(Baseline::Best),
+            _ => Err(format!(
+                "invalid baseline {:?}, expected previous or best",
+                s
+            )),
+        }
+    }
+}
+
+/// A part which took longer than its baseline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Slowdown {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Slowdown {
+    /// How much slower the part got, in percent of the baseline.
+    pub fn percent(&self) -> f64 {
+        if self.before.is_zero() {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, micros: u64) -> PartResult {
+        PartResult::new(2022, day, part, Some(1), Duration::from_micros(micros))
+    }
+
+    #[test]
+    fn test_parse() {
+        let run = Run {
+            timestamp: 1670000000,
+            results: vec![result(1, 1, 10), result(1, 2, 20)],
+        };
+        let contents = format!(
+            "{}{}\n{}\n{}\nnot json\n",
+            RUN_PREFIX,
+            run.timestamp,
+            run.results[0].to_json(),
+            run.results[1].to_json()
+        );
+
+        assert_eq!(History::parse(&contents).runs, [run]);
+        assert_eq!(History::parse("").runs, []);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let history = History {
+            runs: vec![
+                Run {
+                    timestamp: 1,
+                    results: vec![result(1, 1, 100), result(1, 2, 100)],
+                },
+                Run {
+                    timestamp: 2,
+                    results: vec![result(1, 1, 150)],
+                },
+            ],
+        };
+        let results = [result(1, 1, 160), result(1, 2, 105), result(2, 1, 500)];
+
+        let slower = history.regressions(&results, Baseline::Previous, 10.0);
+        assert!(slower.is_empty());
+
+        let slower = history.regressions(&results, Baseline::Best, 10.0);
+        assert_eq!(slower.len(), 1);
+        assert_eq!((slower[0].day, slower[0].part), (1, 1));
+        assert_eq!(slower[0].before, Duration::from_micros(100));
+        assert!((slower[0].percent() - 60.0).abs() < 1e-9);
+
+        let slower = history.regressions(&results, Baseline::Previous, 4.0);
+        assert_eq!(slower.len(), 2);
+    }
+}
@@ -0,0 +1,190 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::params;
+
+/// The year whose files live directly in `src/`, e.g. `src/inputs/01.txt`.  Files of other years
+/// live in a folder named after the year, e.g. `src/2021/inputs/01.txt`.
+pub const DEFAULT_YEAR: i16 = 2022;
+
+/// Environment variable naming a folder which holds the inputs instead of `src/inputs`.  Inputs of
+/// other years than `DEFAULT_YEAR` are read from a subfolder named after the year.
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+/// Flag of a day binary which names the input file to solve, or `-` to read it from stdin.
+pub const INPUT_FLAG: &str = "--input";
+
+/// Returns the `src` folder of this crate, wherever the binary or test is run from.
+pub fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// Returns the folder which holds the files of the given year, `src` for `DEFAULT_YEAR` and
+/// `src/<year>` otherwise.
+pub fn year_dir(year: i16) -> PathBuf {
+    match year {
+        DEFAULT_YEAR => src_dir(),
+        _ => src_dir().join(year.to_string()),
+    }
+}
+
+/// Returns the folder of the library modules which solve the days of the given year, `src/days` for
+/// `DEFAULT_YEAR` and `src/days/y<year>` otherwise.
+pub fn days_dir(year: i16) -> PathBuf {
+    match year {
+        DEFAULT_YEAR => src_dir().join("days"),
+        _ => src_dir().join("days").join(format!("y{}", year)),
+    }
+}
+
+/// Returns the path of the input for the given day: `NN.txt` in the folder named by
+/// `AOC_INPUT_DIR`, or in the year's `inputs` folder by default.
+pub fn input_path(year: i16, day: u8) -> PathBuf {
+    input_path_in(env::var_os(INPUT_DIR_ENV_VAR), year, day)
+}
+
+/// Like `input_path`, with the value of `AOC_INPUT_DIR` given.
+fn input_path_in(input_dir: Option<OsString>, year: i16, day: u8) -> PathBuf {
+    let folder = match (input_dir, year) {
+        (Some(folder), DEFAULT_YEAR) => PathBuf::from(folder),
+        (Some(folder), _) => PathBuf::from(folder).join(year.to_string()),
+        (None, _) => year_dir(year).join("inputs"),
+    };
+    folder.join(format!("{:02}.txt", day))
+}
+
+/// Where to read a day's input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads `--input <PATH>` from the given arguments, consuming it.  A path of `-` reads the
+    /// input from stdin; without the flag the input is read from `input_path(year, day)`.
+    pub fn from_args(
+        year: i16,
+        day: u8,
+        args: &mut pico_args::Arguments,
+    ) -> Result<Self, pico_args::Error> {
+        let source = match args.opt_value_from_str::<_, String>(INPUT_FLAG)? {
+            Some(path) if path == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(input_path(year, day)),
+        };
+        Ok(source)
+    }
+
+    /// Like `from_args`, reading this process's command line.
+    pub fn from_env(year: i16, day: u8) -> Result<Self, pico_args::Error> {
+        InputSource::from_args(year, day, &mut pico_args::Arguments::from_env())
+    }
+
+    /// Reads the input for the given puzzle.  Parameters in a sidecar file next to an input file are
+    /// put in front of it, see `params::Params`.
+    pub fn read(&self, year: i16, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => params::read_with_params(path).map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => InputError::Missing {
+                    year,
+                    day,
+                    path: path.clone(),
+                },
+                _ => InputError::Io(path.display().to_string(), e),
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::Io("stdin".to_string(), e))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Why an input couldn't be read.
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input file at the path, most likely because it wasn't downloaded yet.
+    Missing { year: i16, day: u8, path: PathBuf },
+    /// The input exists but couldn't be read.  Holds a description of the source.
+    Io(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => {
+                write!(
+                    f,
+                    "there is no input for day {} at \"{}\", download it with `cargo download {}",
+                    day,
+                    path.display(),
+                    day
+                )?;
+                if *year != DEFAULT_YEAR {
+                    write!(f, " --year {}", year)?;
+                }
+                write!(f, "`")
+            }
+            InputError::Io(source, e) => {
+                write!(f, "could not read the input from {}: {}", source, e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--input".into(), "-".into()]);
+        let source = InputSource::from_args(DEFAULT_YEAR, 5, &mut args).unwrap();
+        assert_eq!(source, InputSource::Stdin);
+
+        let mut args = pico_args::Arguments::from_vec(vec![]);
+        let source = InputSource::from_args(DEFAULT_YEAR, 5, &mut args).unwrap();
+        assert!(matches!(&source, InputSource::File(path) if path.ends_with("05.txt")));
+
+        assert_eq!(year_dir(2021), src_dir().join("2021"));
+        assert_eq!(
+            input_path_in(None, 2021, 5),
+            src_dir().join("2021").join("inputs").join("05.txt")
+        );
+        assert_eq!(
+            input_path_in(None, DEFAULT_YEAR, 5),
+            src_dir().join("inputs").join("05.txt")
+        );
+        let dir = Some(OsString::from("/aoc"));
+        assert_eq!(
+            input_path_in(dir.clone(), 2021, 5),
+            PathBuf::from("/aoc/2021/05.txt")
+        );
+        assert_eq!(
+            input_path_in(dir, DEFAULT_YEAR, 5),
+            PathBuf::from("/aoc/05.txt")
+        );
+
+        let missing = InputSource::File(src_dir().join("inputs").join("missing.txt"));
+        let err = missing.read(DEFAULT_YEAR, 5).unwrap_err();
+        assert!(matches!(err, InputError::Missing { day: 5, .. }));
+        assert!(err
+            .to_string()
+            .ends_with("download it with `cargo download 5`"));
+        let err = missing.read(2021, 5).unwrap_err();
+        assert!(err.to_string().ends_with("`cargo download 5 --year 2021`"));
+    }
+}
@@ -3,10 +3,26 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use input::{year_dir, InputError, InputSource, DEFAULT_YEAR};
+use results::{IntoAnswer, PartResult};
+
+pub mod answers;
+pub mod aoc_cli;
+pub mod bench;
+pub mod days;
 pub mod helpers;
+pub mod history;
+pub mod input;
+pub mod params;
+pub mod puzzle;
+pub mod results;
+pub mod runner;
+pub mod table;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -15,111 +31,187 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+        use advent_of_code::results::OutputFormat;
+        use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+
+        let (year, day) = advent_of_code::parse_bin_name(env!("CARGO_BIN_NAME"))
+            .unwrap_or((advent_of_code::input::DEFAULT_YEAR, 0));
+
+        let format = OutputFormat::from_env();
+        if format == OutputFormat::Human {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        match advent_of_code::bench::BenchConfig::from_env() {
+            Some(config) => {
+                let (result, stats) = advent_of_code::bench::bench_part(
+                    year,
+                    day,
+                    $part,
+                    $solver,
+                    $input,
+                    config.budget,
+                );
+                // Parsers can only be found for days registered in `days!`.
+                let parse = advent_of_code::days::get(year, day)
+                    .filter(|_| config.time_parse)
+                    .and_then(|s| s.bench_parse($input, config.budget));
+                advent_of_code::bench::print_bench(&result, &stats, parse.as_ref(), format);
+                advent_of_code::answers::verify(&result, format);
+            }
+            None => {
+                let result = advent_of_code::run_part(year, day, $part, $solver, $input);
+                result.print(format);
+                advent_of_code::answers::verify(&result, format);
+            }
+        }
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Returns the name of the binary which solves the given day, e.g. "01" for the `DEFAULT_YEAR` and
+/// "2021-01" for other years.
+pub fn bin_name(year: i16, day: u8) -> String {
+    match year {
+        DEFAULT_YEAR => format!("{:02}", day),
+        _ => format!("{}-{:02}", year, day),
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Reads the year and day back from the name of a day binary, see `bin_name`.
+pub fn parse_bin_name(name: &str) -> Option<(i16, u8)> {
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((DEFAULT_YEAR, name.parse().ok()?)),
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Runs the given solver on the input, timing how long it takes.
+pub fn run_part<R: IntoAnswer>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl FnOnce(&str) -> R,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let outcome = solver(input);
+    let elapsed = timer.elapsed();
+
+    PartResult::new(year, day, part, outcome, elapsed)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Reads the input of the given day of the `DEFAULT_YEAR` for a day binary, see `read_year_input`.
+pub fn read_input(day: u8) -> String {
+    read_year_input(DEFAULT_YEAR, day)
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+/// Reads the input of the given puzzle for a day binary, from the file or stdin named by
+/// `--input`, or else from `input::input_path`.  Exits with an explanation if the input can't be
+/// read.
+pub fn read_year_input(year: i16, day: u8) -> String {
+    let input = InputSource::from_env(year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|source| source.read(year, day).map_err(|e| e.to_string()));
+
+    input.unwrap_or_else(|e| {
+        eprintln!("Failed to read the input: {}", e);
+        process::exit(1);
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn read_file(folder: &str, day: u8) -> String {
+    read_year_file(DEFAULT_YEAR, folder, day)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+/// Like `read_file`, for a day of the given year.  Panics with the path of the file if it can't
+/// be read, and how to download it if it is missing.
+pub fn read_year_file(year: i16, folder: &str, day: u8) -> String {
+    match InputSource::File(year_file_path(year, folder, day)).read(year, day) {
+        Ok(input) => input,
+        // `cargo download` only fetches inputs, examples are copied from the puzzle by hand.
+        Err(InputError::Missing { path, .. }) if folder == "examples" => panic!(
+            "there is no example for day {} at \"{}\", paste the example from the puzzle into it",
+            day,
+            path.display()
+        ),
+        Err(e) => panic!("{}", e),
     }
 }
+
+/// Like `read_file`, but returns an error instead of panicking if the file can't be read.  Files
+/// are found relative to the crate rather than the current directory.
+///
+/// Parameters in a sidecar file next to the input are put in front of it, see `params::Params`.
+pub fn try_read_file(folder: &str, day: u8) -> io::Result<String> {
+    try_read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// Like `try_read_file`, for a day of the given year.  Files of other years than the `DEFAULT_YEAR`
+/// are read from `src/<year>/<folder>`.
+pub fn try_read_year_file(year: i16, folder: &str, day: u8) -> io::Result<String> {
+    params::read_with_params(&year_file_path(year, folder, day))
+}
+
+fn year_file_path(year: i16, folder: &str, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// Reads a named example for the given day, from either `src/examples/NN-<name>.txt` or
+/// `src/examples/NN/<name>.txt`.  An empty name reads the default example, `src/examples/NN.txt`.
+pub fn read_example(day: u8, name: &str) -> String {
+    read_year_example(DEFAULT_YEAR, day, name)
+}
+
+/// Like `read_example`, for a day of the given year.
+pub fn read_year_example(year: i16, day: u8, name: &str) -> String {
+    if name.is_empty() {
+        return read_year_file(year, "examples", day);
+    }
+
+    let folder = year_dir(year).join("examples");
+    let candidates = [
+        folder.join(format!("{:02}-{}.txt", day, name)),
+        folder
+            .join(format!("{:02}", day))
+            .join(format!("{}.txt", name)),
+    ];
+
+    candidates
+        .iter()
+        .find_map(|path| params::read_with_params(path).ok())
+        .unwrap_or_else(|| panic!("could not open example {:?} of day {}", name, day))
+}
+
+/// Runs a solver over several examples of a day and checks each answer, naming the example which
+/// failed.  Example names are read with `read_example`, or `read_year_example` for days of another
+/// year than the `DEFAULT_YEAR`, which start with `year <year>;`.
+///
+/// ```ignore
+/// test_examples!(6, part_one, [
+///     "" => Some(7),
+///     "b" => Some(5),
+/// ]);
+/// test_examples!(year 2021; 6, part_one, [
+///     "" => Some(5934),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! test_examples {
+    (year $year:expr; $day:expr, $solver:path, [$($name:expr => $expected:expr),* $(,)?]) => {{
+        $(
+            let input = $crate::read_year_example($year, $day, $name);
+            assert_eq!(
+                $solver(&input),
+                $expected,
+                "example {:?} of day {}",
+                $name,
+                $day
+            );
+        )*
+    }};
+    ($day:expr, $solver:path, [$($name:expr => $expected:expr),* $(,)?]) => {
+        $crate::test_examples!(year $crate::input::DEFAULT_YEAR; $day, $solver, [
+            $($name => $expected),*
+        ])
+    };
+}
@@ -2,47 +2,307 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers;
+use advent_of_code::bench::{self, BenchConfig, Stats};
+use advent_of_code::days::{self, Solution};
+use advent_of_code::history::{self, Baseline, History, Run};
+use advent_of_code::input::{input_path, InputSource};
+use advent_of_code::results::{OutputFormat, PartResult, JSON_FLAG};
+use advent_of_code::runner::{self, DaySelection, SortOrder};
+use advent_of_code::table::{self, TimingTable};
+use advent_of_code::{bin_name, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    /// Only run the solutions of this year, instead of every year.
+    year: Option<i16>,
+    /// The days to run, all of them by default.
+    days: DaySelection,
+    /// Only run this part, instead of both.
+    part: Option<u8>,
+    /// Leave out days without any solved part, instead of printing "Not solved.".
+    skip_unsolved: bool,
+    /// The number of threads running days concurrently, if more than one.
+    threads: Option<usize>,
+    /// The order in which the days are printed.
+    sort: SortOrder,
+    /// Print a table of every part's answer and time after the run.
+    table: bool,
+    /// Write the table into the README instead of printing it.
+    readme: bool,
+    /// Don't record the run's timings in the history file.
+    no_history: bool,
+    /// The recorded run to compare the timings against.
+    compare: Baseline,
+    /// How much slower than the baseline a part may get, in percent, before it is flagged.
+    threshold: f64,
+    bench: Option<BenchConfig>,
+}
+
+/// The outcome of running one day.
+#[derive(Default)]
+struct DayRun {
+    /// Results for each part which ran, along with their statistics in benchmark mode.
+    parts: Vec<(PartResult, Option<Stats>)>,
+    /// Statistics for the day's parser, if it was benchmarked separately.
+    parse: Option<Stats>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // Consumed here so it isn't reported as unknown; `OutputFormat::from_env` picks it up.
+    args.contains(JSON_FLAG);
+
+    let parallel = args.contains(["-p", "--parallel"]);
+    let threads: Option<usize> = args.opt_value_from_str(["-j", "--threads"])?;
+
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        days: args
+            .opt_value_from_str(["-d", "--days"])?
+            .unwrap_or_default(),
+        part: args.opt_value_from_fn("--part", |part| match part {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err(format!("invalid part {:?}, expected 1 or 2", part)),
+        })?,
+        skip_unsolved: args.contains(["-s", "--skip-unsolved"]),
+        threads: threads.or(parallel.then(runner::default_threads)),
+        sort: args.opt_value_from_str("--sort")?.unwrap_or_default(),
+        table: args.contains(["-t", "--table"]),
+        readme: args.contains("--readme"),
+        no_history: args.contains("--no-history"),
+        compare: args.opt_value_from_str("--compare")?.unwrap_or_default(),
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+        bench: BenchConfig::from_args(&mut args)?,
+    };
+
+    let remaining = args.finish();
+    if let Some(arg) = remaining.first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", arg),
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Runs the selected parts of the given day in this process, benchmarking them if configured.
+/// Returns no results if the day has no input file; parts which panic are left out.
+fn run_day(solution: &dyn Solution, args: &Args) -> DayRun {
+    let bench = args.bench;
+    let (year, day) = (solution.year(), solution.day());
+    let input = match InputSource::File(input_path(year, day)).read(year, day) {
+        Ok(input) => input,
+        Err(_) => return DayRun::default(),
+    };
+
+    let parts = (1..=2)
+        .filter(|&part| args.part.is_none_or(|only| only == part))
+        .filter_map(|part| {
+            panic::catch_unwind(AssertUnwindSafe(|| match bench {
+                Some(config) => {
+                    let (result, stats) = solution.bench(part, &input, config.budget);
+                    (result, Some(stats))
+                }
+                None => (solution.run(part, &input), None),
+            }))
+            .ok()
+        })
+        .collect();
+
+    let parse = bench
+        .filter(|config| config.time_parse)
+        .and_then(|config| solution.bench_parse(&input, config.budget));
+
+    DayRun { parts, parse }
+}
+
+fn print_day(day: u8, run: &DayRun, format: OutputFormat) {
+    if format == OutputFormat::Human {
+        println!("----------");
+        println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+        println!("----------");
+
+        if run.parts.is_empty() {
+            println!("Not solved.");
+        }
+    }
+
+    for (result, stats) in &run.parts {
+        if format == OutputFormat::Human {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+        }
+        match stats {
+            Some(stats) => bench::print_bench(result, stats, run.parse.as_ref(), format),
+            None => result.print(format),
+        }
+        answers::verify(result, format);
+    }
+}
+
+impl DayRun {
+    /// The time taken by the day's solved parts.
+    fn elapsed(&self) -> Duration {
+        self.parts
+            .iter()
+            .filter(|(result, _)| result.is_solved())
+            .map(|(result, _)| result.elapsed)
+            .sum()
+    }
+}
+
+/// How one year went: the number of solved parts and the time they took, along with the results
+/// of every part which ran.
+#[derive(Default)]
+struct YearSummary {
+    solved: usize,
+    elapsed: Duration,
+    results: Vec<PartResult>,
+}
+
+/// Runs and prints the selected days of the given year.
+fn run_year(year: i16, args: &Args, format: OutputFormat) -> YearSummary {
+    let mut summary = YearSummary::default();
+    let mut report = |day: u8, run: DayRun| {
+        let solved = run.parts.iter().filter(|(r, _)| r.is_solved()).count();
+        if solved == 0 && args.skip_unsolved {
+            return;
+        }
+        print_day(day, &run, format);
+        summary.solved += solved;
+        summary.elapsed += run.elapsed();
+        summary
+            .results
+            .extend(run.parts.into_iter().map(|(result, _)| result));
+    };
+
+    let solutions: Vec<&dyn Solution> = days::year(year)
+        .iter()
+        .copied()
+        .filter(|solution| args.days.contains(solution.day()))
+        .collect();
+
+    // Days are printed as soon as they ran, unless they have to be sorted or run concurrently.
+    if args.threads.is_none() && args.sort == SortOrder::Day {
+        for solution in solutions {
+            report(solution.day(), run_day(solution, args));
+        }
+        return summary;
+    }
+
+    let threads = args.threads.unwrap_or(1);
+    let runs = runner::run_pool(&solutions, threads, |&solution| run_day(solution, args));
+    let mut runs: Vec<(u8, DayRun)> = solutions
+        .iter()
+        .zip(runs)
+        .map(|(solution, run)| (solution.day(), run.unwrap_or_default()))
+        .collect();
+
+    if args.sort == SortOrder::Time {
+        runs.sort_by_key(|(_, run)| std::cmp::Reverse(run.elapsed()));
+    }
+    for (day, run) in runs {
+        report(day, run);
+    }
+
+    summary
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let format = OutputFormat::from_env();
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+    let years: Vec<i16> = match args.year {
+        Some(year) => vec![year],
+        None => days::YEARS.iter().map(|(year, _)| *year).collect(),
+    };
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    let mut total = Duration::ZERO;
+    let mut results = Vec::new();
+    for &year in &years {
+        if format == OutputFormat::Human && years.len() > 1 {
+            println!("{}=== {} ==={}", ANSI_BOLD, year, ANSI_RESET);
+        }
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+        let summary = run_year(year, &args, format);
+        total += summary.elapsed;
+        results.extend(summary.results);
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        if format == OutputFormat::Human {
+            println!(
+                "{}{}:{} {} parts solved in {}{:.2}ms{}",
+                ANSI_BOLD,
+                year,
+                ANSI_RESET,
+                summary.solved,
+                ANSI_ITALIC,
+                summary.elapsed.as_secs_f64() * 1000_f64,
+                ANSI_RESET
+            );
+        }
+    }
+
+    if format == OutputFormat::Human {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
 
+    let history_path = history::history_path();
+    let history = History::load(&history_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read the history {:?}: {}", history_path, e);
+        History::default()
+    });
+    if format == OutputFormat::Human {
+        for slowdown in history.regressions(&results, args.compare, args.threshold) {
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "⚠️  {}Day {} part {}{} got slower: {:.2?} → {:.2?} (+{:.0}%)",
+                ANSI_BOLD,
+                bin_name(slowdown.year, slowdown.day),
+                slowdown.part,
+                ANSI_RESET,
+                slowdown.before,
+                slowdown.after,
+                slowdown.percent()
             );
+        }
+    }
+    if !args.no_history && !results.is_empty() {
+        if let Err(e) = History::append(&history_path, &Run::now(results.clone())) {
+            eprintln!("Failed to record the run in {:?}: {}", history_path, e);
+        }
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let timings = TimingTable::new(&results);
+    if args.readme {
+        let readme = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+        match table::update_readme(&readme, &timings) {
+            // On stderr, as stdout may be JSON.
+            Ok(()) => eprintln!("Wrote the timing table to {:?}", readme),
+            Err(e) => {
+                eprintln!("Failed to write the timing table to {:?}: {}", readme, e);
+                process::exit(1);
             }
-        })
-        .sum();
+        }
+    } else if args.table && format == OutputFormat::Human {
+        println!();
+        print!("{}", timings);
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    answers::exit_on_regression();
 }
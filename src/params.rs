@@ -0,0 +1,150 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Prefix of the header lines which carry puzzle parameters at the start of an input.
+pub const HEADER_PREFIX: &str = "#!";
+
+/// Extension of the sidecar file which holds the parameters for an input, eg. `15.params` next to
+/// `15.txt`.
+pub const SIDECAR_EXTENSION: &str = "params";
+
+/// Parameters which differ between inputs, such as the row to inspect in the example and in the
+/// real puzzle.
+///
+/// They are given as `key = value` lines, either in a sidecar file next to the input or in header
+/// lines starting with `#!` at the top of the input itself.  `read_file` turns the sidecar file into
+/// a header, so solvers only need to split it off:
+///
+/// ```
+/// use advent_of_code::params::Params;
+///
+/// let (params, input) = Params::split("#! row = 10\n1,2\n3,4");
+/// assert_eq!(params.get_or("row", 2000000), 10);
+/// assert_eq!(input, "1,2\n3,4");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+
+impl Params {
+    /// Splits the parameter header off an input.  Inputs without a header have no parameters.
+    pub fn split(input: &str) -> (Self, &str) {
+        let mut params = Params::default();
+        let mut rest = input;
+
+        while let Some(line) = rest.strip_prefix(HEADER_PREFIX) {
+            let (line, remainder) = line.split_once('\n').unwrap_or((line, ""));
+            params.insert_line(line);
+            rest = remainder;
+        }
+
+        (params, rest)
+    }
+
+    /// Returns the value of a parameter, or `None` if it isn't set.
+    ///
+    /// Panics if the value can't be parsed, as a solver can't do anything sensible with it.
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        let (_, value) = self.values.iter().find(|(k, _)| k == key)?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => panic!("invalid value {:?} for puzzle parameter {:?}", value, key),
+        }
+    }
+
+    /// Returns the value of a parameter, or the default (usually the value for the real input).
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Renders the parameters as a header to put in front of an input.
+    pub fn to_header(&self) -> String {
+        self.values
+            .iter()
+            .map(|(key, value)| format!("{} {} = {}\n", HEADER_PREFIX, key, value))
+            .collect()
+    }
+
+    fn insert_line(&mut self, line: &str) {
+        if let Some((key, value)) = line.split_once('=') {
+            self.values
+                .push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parses a sidecar file, with one `key = value` per line.  Empty lines and lines starting
+    /// with `#` are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if !line.contains('=') {
+                return Err(format!("line {}: expected `key = value`", i + 1));
+            }
+            params.insert_line(line);
+        }
+
+        Ok(params)
+    }
+}
+
+/// Reads an input file, putting the parameters from its sidecar file (if any) in front of it as a
+/// header.
+pub fn read_with_params(path: &Path) -> io::Result<String> {
+    let input = fs::read_to_string(path)?;
+
+    let sidecar = match fs::read_to_string(path.with_extension(SIDECAR_EXTENSION)) {
+        Ok(sidecar) => sidecar,
+        Err(_) => return Ok(input),
+    };
+    let params = sidecar
+        .parse::<Params>()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(params.to_header() + &input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_params() {
+        let (params, input) = Params::split("#! y = 10\n#!max_coord=20\nSensor at x=2");
+        assert_eq!(params.get::<i64>("y"), Some(10));
+        assert_eq!(params.get::<i64>("max_coord"), Some(20));
+        assert_eq!(params.get::<i64>("x"), None);
+        assert_eq!(input, "Sensor at x=2");
+
+        let (params, input) = Params::split("#.#\n..#");
+        assert_eq!(params, Params::default());
+        assert_eq!(input, "#.#\n..#");
+    }
+
+    #[test]
+    fn test_sidecar_round_trip() {
+        let params = "# example values\ny = 10\n\nmax_coord = 20\n"
+            .parse::<Params>()
+            .unwrap();
+        let header = params.to_header();
+        assert_eq!(header, "#! y = 10\n#! max_coord = 20\n");
+        assert_eq!(Params::split(&header).0, params);
+
+        assert!("y: 10".parse::<Params>().is_err());
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Heading which aoc-cli writes before the description of the second part.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// The examples and expected answers found in a puzzle description, as written by
+/// `cargo download <day> --puzzle`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The title of the puzzle, e.g. "Calorie Counting".
+    pub title: Option<String>,
+    /// Code blocks which follow a paragraph containing "For example", in order of appearance and
+    /// without duplicates.
+    pub examples: Vec<String>,
+    /// The expected answer for the example of each part described so far.
+    pub answers: Vec<Option<String>>,
+}
+
+impl Puzzle {
+    pub fn parse(markdown: &str) -> Self {
+        let mut examples: Vec<String> = vec![];
+        for block in example_blocks(markdown) {
+            if !examples.contains(&block) {
+                examples.push(block);
+            }
+        }
+
+        let answers = split_parts(markdown)
+            .into_iter()
+            .map(example_answer)
+            .collect();
+
+        Puzzle {
+            title: title(markdown),
+            examples,
+            answers,
+        }
+    }
+}
+
+/// Returns the title from the heading of the first part, "--- Day 1: Calorie Counting ---".
+fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches('-').trim().to_string())
+}
+
+/// Splits the description into the text of each part.
+fn split_parts(markdown: &str) -> Vec<&str> {
+    let heading = markdown
+        .match_indices(PART_TWO_HEADING)
+        .map(|(i, _)| markdown[..i].rfind('\n').map_or(0, |nl| nl + 1))
+        .next();
+
+    match heading {
+        Some(start) => vec![&markdown[..start], &markdown[start..]],
+        None => vec![markdown],
+    }
+}
+
+/// Returns the contents of each fenced code block which follows a paragraph containing
+/// "For example".  Trailing blank lines are dropped, as aoc-cli adds one to every block.
+fn example_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut after_example = false;
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match (&mut block, is_fence) {
+            (None, true) => block = Some(vec![]),
+            (None, false) => {
+                if line.to_lowercase().contains("for example") {
+                    after_example = true;
+                } else if !line.trim().is_empty() {
+                    after_example = false;
+                }
+            }
+            (Some(lines), false) => lines.push(line),
+            (Some(lines), true) => {
+                if after_example {
+                    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+                        lines.pop();
+                    }
+                    blocks.push(lines.join("\n"));
+                }
+                after_example = false;
+                block = None;
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Returns the last emphasized code value of a part, which is the answer for its example.  The
+/// final question and the text after "Your puzzle answer was" are ignored.
+fn example_answer(part: &str) -> Option<String> {
+    let text = part
+        .find("Your puzzle answer was")
+        .map_or(part, |i| &part[..i]);
+
+    // aoc-cli writes `<code><em>..</em></code>` as "`*..*`" and the reverse as "*`..`*".
+    ["`*", "*`"]
+        .iter()
+        .filter_map(|open| {
+            let close: String = open.chars().rev().collect();
+            let start = text.rfind(open)? + open.len();
+            let len = text[start..].find(&close)?;
+            Some((start, &text[start..start + len]))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, value)| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKDOWN: &str = "\\--- Day 1: Test ---
+----------
+
+Some text with an emphasized `*value*`.
+
+```
+not an example
+```
+
+For example, suppose you have the following list:
+
+```
+1
+2
+3
+
+```
+
+The sum of these is `*6*`.
+
+*What is the sum?*
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+----------
+
+For example:
+
+```
+1
+2
+3
+
+```
+
+The product is *`6`*, in the above example. Another example:
+
+```
+4
+5
+```
+
+*What is the product?*
+";
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        assert_eq!(puzzle.title.as_deref(), Some("Test"));
+        assert_eq!(puzzle.examples, vec!["1\n2\n3".to_string()]);
+        assert_eq!(
+            puzzle.answers,
+            vec![Some("6".to_string()), Some("6".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_puzzle_without_examples() {
+        let puzzle = Puzzle::parse("\\--- Day 1: Test ---\n\n*What is it?*\n");
+        assert_eq!(puzzle.title.as_deref(), Some("Test"));
+        assert_eq!(puzzle.examples, Vec::<String>::new());
+        assert_eq!(puzzle.answers, vec![None]);
+    }
+}
@@ -0,0 +1,437 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display, Write};
+use std::time::Duration;
+
+use crate::input::DEFAULT_YEAR;
+use crate::{ANSI_ITALIC, ANSI_RESET};
+
+/// Environment variable which selects how results are printed.  Set it to `json` to get one JSON
+/// object per line instead of the human readable output.
+pub const OUTPUT_ENV_VAR: &str = "AOC_OUTPUT";
+
+/// Command line flag which has the same effect as setting `AOC_OUTPUT=json`.
+pub const JSON_FLAG: &str = "--json";
+
+/// How the results of a solution are printed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Formatted text meant for a terminal.
+    Human,
+    /// One JSON object per line, as produced by `PartResult::to_json`.
+    Json,
+}
+
+impl OutputFormat {
+    /// Determines the output format from the `AOC_OUTPUT` environment variable and the `--json`
+    /// command line flag.  Defaults to `OutputFormat::Human`.
+    pub fn from_env() -> Self {
+        let env_json = env::var(OUTPUT_ENV_VAR)
+            .map(|v| v.eq_ignore_ascii_case("json"))
+            .unwrap_or(false);
+
+        if env_json || env::args().any(|a| a == JSON_FLAG) {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Human
+        }
+    }
+}
+
+/// Values which solvers can return.  Implemented for `Option<T>`, where `None` means the part is
+/// not solved, for plain answers such as integers and strings, and for `Result`s of either, where
+/// an error means the solver failed.
+///
+/// A solver can therefore return `Option<u32>`, `Result<u32, E>` or `Result<Option<u32>, E>` for
+/// any error type `E` which implements `Display`.
+pub trait IntoAnswer {
+    /// Converts the returned value into the answer (`None` if not solved) or an error message.
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|a| a.to_string()))
+    }
+}
+
+impl<T: IntoAnswer, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map_err(|e| e.to_string())?.into_answer()
+    }
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<Option<String>, String> {
+                    Ok(Some(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
+
+/// The result of running one part of a day's solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    /// The year of the puzzle.
+    pub year: i16,
+    /// The day of the puzzle.
+    pub day: u8,
+    /// The part of the puzzle (1 or 2).
+    pub part: u8,
+    /// The answer produced by the solver, or `None` if the part is not solved.
+    pub answer: Option<String>,
+    /// The error the solver failed with, if any.
+    pub error: Option<String>,
+    /// How long the solver took to run.
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    /// Creates a result from the value returned by a solver.
+    pub fn new(year: i16, day: u8, part: u8, outcome: impl IntoAnswer, elapsed: Duration) -> Self {
+        let (answer, error) = match outcome.into_answer() {
+            Ok(answer) => (answer, None),
+            Err(error) => (None, Some(error)),
+        };
+
+        PartResult {
+            year,
+            day,
+            part,
+            answer,
+            error,
+            elapsed,
+        }
+    }
+
+    /// Whether or not the solver produced an answer.
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Prints the result in the given format.
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Human => print!("{}", self),
+            OutputFormat::Json => println!("{}", self.to_json()),
+        }
+    }
+
+    /// Serializes the result as a single-line JSON object, in the format:
+    /// ```text
+    /// {"year":2022,"day":1,"part":1,"answer":"24000","solved":true,"error":null,"elapsed_ns":1234}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let optional = |value: &Option<String>| match value {
+            Some(value) => json_string(value),
+            None => String::from("null"),
+        };
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"solved\":{},\"error\":{},\"elapsed_ns\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            optional(&self.answer),
+            self.is_solved(),
+            optional(&self.error),
+            self.elapsed.as_nanos()
+        )
+    }
+
+    /// Reads a result back from a line produced by `PartResult::to_json`.  Returns `None` if the
+    /// line is not such a result.
+    pub fn from_json(line: &str) -> Option<Self> {
+        let fields = parse_flat_object(line.trim())?;
+        let field = |name: &str| fields.iter().find(|(k, _)| k == name).map(|(_, v)| v);
+
+        let number = |name: &str| match field(name)? {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        };
+
+        let optional = |value: &JsonValue| match value {
+            JsonValue::String(s) => Some(Some(s.clone())),
+            JsonValue::Null => Some(None),
+            _ => None,
+        };
+
+        let answer = optional(field("answer")?)?;
+        // Results written before solvers could fail have no error field.
+        let error = match field("error") {
+            Some(value) => optional(value)?,
+            None => None,
+        };
+
+        if field("solved")? != &JsonValue::Bool(answer.is_some()) {
+            return None;
+        }
+
+        // Results written before multi-year support have no year field.
+        let year = match number("year") {
+            Some(year) => year.try_into().ok()?,
+            None => DEFAULT_YEAR,
+        };
+
+        Some(PartResult {
+            year,
+            day: number("day")?.try_into().ok()?,
+            part: number("part")?.try_into().ok()?,
+            answer,
+            error,
+            elapsed: Duration::from_nanos(number("elapsed_ns")?),
+        })
+    }
+}
+
+impl Display for PartResult {
+    /// Formats the result the way `solve!` prints it to a terminal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => writeln!(
+                f,
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            ),
+            (None, Some(error)) => writeln!(f, "failed: {}", error),
+            (None, None) => writeln!(f, "not solved."),
+        }
+    }
+}
+
+/// Values which can appear in the flat JSON objects produced by `PartResult::to_json`.
+#[derive(Debug, PartialEq)]
+enum JsonValue {
+    String(String),
+    Number(u64),
+    Bool(bool),
+    Null,
+}
+
+/// Quotes and escapes the given string so it is a valid JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+
+    out
+}
+
+/// Parses a JSON object whose values are all strings, unsigned integers, booleans or null.
+///
+/// This is not a general JSON parser; it only needs to understand what `PartResult::to_json`
+/// writes.
+fn parse_flat_object(s: &str) -> Option<Vec<(String, JsonValue)>> {
+    let mut chars = s.chars().peekable();
+    let mut fields = Vec::new();
+
+    let skip_ws = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    };
+
+    skip_ws(&mut chars);
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    loop {
+        skip_ws(&mut chars);
+        if chars.next_if_eq(&'}').is_some() {
+            break;
+        }
+        if !fields.is_empty() {
+            // Fields after the first must be separated by a comma.
+            if chars.next()? != ',' {
+                return None;
+            }
+            skip_ws(&mut chars);
+        }
+
+        let key = parse_string(&mut chars)?;
+        skip_ws(&mut chars);
+        if chars.next()? != ':' {
+            return None;
+        }
+        skip_ws(&mut chars);
+
+        let value = match chars.peek()? {
+            '"' => JsonValue::String(parse_string(&mut chars)?),
+            c if c.is_ascii_digit() => {
+                let mut num = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                    num.push(c);
+                }
+                JsonValue::Number(num.parse().ok()?)
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                    word.push(c);
+                }
+                match &word[..] {
+                    "true" => JsonValue::Bool(true),
+                    "false" => JsonValue::Bool(false),
+                    "null" => JsonValue::Null,
+                    _ => return None,
+                }
+            }
+        };
+
+        fields.push((key, value));
+    }
+
+    skip_ws(&mut chars);
+    match chars.next() {
+        None => Some(fields),
+        Some(_) => None,
+    }
+}
+
+/// Parses a quoted JSON string, handling the escapes produced by `json_string`.
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                '/' => out.push('/'),
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&hex, 16).ok()?;
+                    out.push(char::from_u32(code)?);
+                }
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let solved = PartResult {
+            year: 2021,
+            day: 10,
+            part: 2,
+            answer: Some(String::from(".#..\n\"q\"\\")),
+            error: None,
+            elapsed: Duration::from_nanos(1_450_000),
+        };
+        assert_eq!(
+            solved.to_json(),
+            r#"{"year":2021,"day":10,"part":2,"answer":".#..\n\"q\"\\","solved":true,"error":null,"elapsed_ns":1450000}"#
+        );
+        assert_eq!(PartResult::from_json(&solved.to_json()), Some(solved));
+
+        let unsolved = PartResult {
+            year: DEFAULT_YEAR,
+            day: 3,
+            part: 1,
+            answer: None,
+            error: None,
+            elapsed: Duration::from_nanos(70),
+        };
+        assert_eq!(
+            unsolved.to_json(),
+            r#"{"year":2022,"day":3,"part":1,"answer":null,"solved":false,"error":null,"elapsed_ns":70}"#
+        );
+        assert_eq!(
+            PartResult::from_json(&unsolved.to_json()),
+            Some(unsolved.clone())
+        );
+
+        let failed = PartResult {
+            error: Some(String::from("invalid input")),
+            ..unsolved.clone()
+        };
+        assert_eq!(PartResult::from_json(&failed.to_json()), Some(failed));
+
+        let old = r#"{"day":3,"part":1,"answer":null,"solved":false,"elapsed_ns":70}"#;
+        assert_eq!(PartResult::from_json(old), Some(unsolved));
+    }
+
+    #[test]
+    fn test_into_answer() {
+        let elapsed = Duration::ZERO;
+
+        let result = PartResult::new(DEFAULT_YEAR, 1, 1, Some(24000), elapsed);
+        assert_eq!(result.answer.as_deref(), Some("24000"));
+
+        let result = PartResult::new(DEFAULT_YEAR, 1, 1, None::<u32>, elapsed);
+        assert!(!result.is_solved());
+        assert_eq!(result.error, None);
+        assert_eq!(result.to_string(), "not solved.\n");
+
+        let result = PartResult::new(DEFAULT_YEAR, 1, 2, Ok::<_, String>(45000), elapsed);
+        assert_eq!(result.answer.as_deref(), Some("45000"));
+
+        let result = PartResult::new(DEFAULT_YEAR, 1, 2, Ok::<Option<u32>, String>(None), elapsed);
+        assert!(!result.is_solved());
+
+        let result = PartResult::new(
+            DEFAULT_YEAR,
+            1,
+            2,
+            Err::<u32, _>("line 3: expected a number"),
+            elapsed,
+        );
+        assert!(!result.is_solved());
+        assert_eq!(result.to_string(), "failed: line 3: expected a number\n");
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_output() {
+        assert_eq!(PartResult::from_json("🎄 Part 1 🎄"), None);
+        assert_eq!(PartResult::from_json("0 (elapsed: 755µs)"), None);
+        assert_eq!(PartResult::from_json(r#"{"day":1,"part":1}"#), None);
+        assert_eq!(PartResult::from_json(r#"{"day":1} trailing"#), None);
+    }
+
+    #[test]
+    fn test_parse_flat_object() {
+        let fields = parse_flat_object(r#"{ "day": 1, "answer": "a" }"#).unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(parse_flat_object("{}"), Some(vec![]));
+
+        assert_eq!(parse_flat_object(r#"{"day":1 "part":1}"#), None);
+        assert_eq!(parse_flat_object(r#"{"day":1,}"#), None);
+        assert_eq!(parse_flat_object(r#"{,"day":1}"#), None);
+    }
+}
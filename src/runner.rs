@@ -0,0 +1,162 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Which days the `all` runner runs, given as a comma-separated list of days and ranges, e.g.
+/// "1-5,8,20-".  Open ranges extend to the first or last day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection {
+    ranges: Vec<RangeInclusive<u8>>,
+}
+
+impl DaySelection {
+    /// Selects every day.
+    pub fn all() -> Self {
+        DaySelection {
+            ranges: vec![1..=25],
+        }
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.ranges.iter().any(|range| range.contains(&day))
+    }
+}
+
+impl Default for DaySelection {
+    fn default() -> Self {
+        DaySelection::all()
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |value: &str, default: u8| match value.trim() {
+            "" => Ok(default),
+            value => value
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("invalid day {:?} in {:?}, expected 1 to 25", value, s)),
+        };
+
+        let ranges = s
+            .split(',')
+            .map(|range| match range.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (day(start, 1)?, day(end, 25)?);
+                    if start > end {
+                        return Err(format!(
+                            "range {:?} in {:?} ends before it starts",
+                            range, s
+                        ));
+                    }
+                    Ok(start..=end)
+                }
+                None if range.trim().is_empty() => Err(format!("missing day in {:?}", s)),
+                None => day(range, 0).map(|day| day..=day),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DaySelection { ranges })
+    }
+}
+
+/// The order in which the `all` runner prints the days.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// By day, printing each day as soon as it ran.
+    #[default]
+    Day,
+    /// Slowest first, once every day ran.
+    Time,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortOrder::Day),
+            "time" => Ok(SortOrder::Time),
+            _ => Err(format!("invalid sort order {:?}, expected day or time", s)),
+        }
+    }
+}
+
+/// Returns the number of threads to use when none is given: one per available core.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Runs every job on a pool of `threads` worker threads and returns the outputs in the order of
+/// the jobs.  Jobs which panic have no output.
+pub fn run_pool<T: Sync, R: Send>(
+    jobs: &[T],
+    threads: usize,
+    run: impl Fn(&T) -> R + Sync,
+) -> Vec<Option<R>> {
+    let next = AtomicUsize::new(0);
+    let outputs = Mutex::new((0..jobs.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                let output = panic::catch_unwind(AssertUnwindSafe(|| run(job))).ok();
+                outputs.lock().unwrap()[i] = output;
+            });
+        }
+    });
+
+    outputs.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        let days: DaySelection = "1-3,8,20-".parse().unwrap();
+        let selected: Vec<u8> = (1..=25).filter(|&day| days.contains(day)).collect();
+        assert_eq!(selected, [1, 2, 3, 8, 20, 21, 22, 23, 24, 25]);
+
+        let days: DaySelection = "-2".parse().unwrap();
+        assert!(days.contains(1) && days.contains(2) && !days.contains(3));
+
+        assert!("1,x".parse::<DaySelection>().is_err());
+        assert!("1,,2".parse::<DaySelection>().is_err());
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("30".parse::<DaySelection>().is_err());
+        assert!("20-26".parse::<DaySelection>().is_err());
+        assert!("9-3".parse::<DaySelection>().is_err());
+        assert!("5-5".parse::<DaySelection>().is_ok());
+        assert!(DaySelection::all().contains(25));
+    }
+
+    #[test]
+    fn test_run_pool() {
+        let jobs: Vec<u64> = (1..=20).collect();
+        let outputs = run_pool(&jobs, 4, |&n| {
+            assert!(n != 13, "unlucky");
+            n * n
+        });
+
+        assert_eq!(outputs.len(), 20);
+        assert_eq!(outputs[1], Some(4));
+        assert_eq!(outputs[12], None);
+        assert_eq!(outputs[19], Some(400));
+    }
+}
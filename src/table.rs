@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::DEFAULT_YEAR;
+use crate::results::PartResult;
+use std::time::Duration;
+use std::{fmt, fs, io, path::Path};
+
+/// Marks the start of the timing table in the README.
+pub const README_TABLE_START: &str = "<!--- benchmarking table --->";
+/// Marks the end of the timing table in the README.
+pub const README_TABLE_END: &str = "<!--- benchmarking table end --->";
+
+/// A summary of the runner's results: one row per part, with its answer, its time and its share of
+/// the total time.
+pub struct TimingTable<'a> {
+    results: &'a [PartResult],
+    total: Duration,
+}
+
+impl<'a> TimingTable<'a> {
+    pub fn new(results: &'a [PartResult]) -> Self {
+        let total = results
+            .iter()
+            .filter(|result| result.is_solved())
+            .map(|result| result.elapsed)
+            .sum();
+
+        TimingTable { results, total }
+    }
+
+    /// The cells of the table, the header first and the total last.
+    fn rows(&self) -> Vec<[String; 5]> {
+        let mut rows = vec![["Day", "Part", "Answer", "Time", "Share"].map(String::from)];
+
+        for result in self.results {
+            let day = match result.year {
+                DEFAULT_YEAR => format!("{}", result.day),
+                year => format!("{} {}", year, result.day),
+            };
+
+            let (answer, time, share) = match (&result.answer, &result.error) {
+                (Some(answer), _) => (
+                    short_answer(answer),
+                    format!("{:.2?}", result.elapsed),
+                    share(result.elapsed, self.total),
+                ),
+                (None, Some(_)) => ("failed".into(), "-".into(), "-".into()),
+                (None, None) => ("not solved".into(), "-".into(), "-".into()),
+            };
+
+            rows.push([day, result.part.to_string(), answer, time, share]);
+        }
+
+        rows.push([
+            "Total".into(),
+            String::new(),
+            String::new(),
+            format!("{:.2?}", self.total),
+            share(self.total, self.total),
+        ]);
+        rows
+    }
+
+    /// Formats the table as markdown, for the README.
+    pub fn to_markdown(&self) -> String {
+        let rows = self.rows();
+        let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+
+        let mut table = line(&rows[0]);
+        table.push_str("| --- | --- | --- | ---: | ---: |\n");
+        for row in &rows[1..rows.len() - 1] {
+            let escaped = row.clone().map(|cell| cell.replace('|', "\\|"));
+            table.push_str(&line(&escaped));
+        }
+        let total = rows[rows.len() - 1]
+            .clone()
+            .map(|cell| match cell.as_str() {
+                "" => cell,
+                _ => format!("**{}**", cell),
+            });
+        table.push_str(&line(&total));
+        table
+    }
+}
+
+/// Formats the table with aligned columns, for the terminal.
+impl fmt::Display for TimingTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = self.rows();
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if i == 1 || i == rows.len() - 1 {
+                let rule: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+                writeln!(f, "{}", rule.join("-+-"))?;
+            }
+            writeln!(
+                f,
+                "{:<w0$} | {:>w1$} | {:<w2$} | {:>w3$} | {:>w4$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                row[4],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+                w4 = widths[4],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps table cells on one line: answers spanning several lines only show their first one.
+fn short_answer(answer: &str) -> String {
+    match answer.trim().split_once('\n') {
+        Some((first, _)) => format!("{}…", first.trim_end()),
+        None => answer.to_string(),
+    }
+}
+
+fn share(elapsed: Duration, total: Duration) -> String {
+    if total.is_zero() {
+        return "-".into();
+    }
+    format!(
+        "{:.1}%",
+        elapsed.as_secs_f64() / total.as_secs_f64() * 100.0
+    )
+}
+
+/// Replaces the contents between `README_TABLE_START` and `README_TABLE_END` with the given table.
+/// Returns `None` if the markers are missing.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(README_TABLE_START)? + README_TABLE_START.len();
+    let end = start + readme[start..].find(README_TABLE_END)?;
+
+    Some(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+/// Writes the table into the README at the given path, between the table markers.
+pub fn update_readme(path: &Path, table: &TimingTable) -> io::Result<()> {
+    let readme = fs::read_to_string(path)?;
+    let updated = replace_table(&readme, &table.to_markdown()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "missing the markers {} and {}",
+                README_TABLE_START, README_TABLE_END
+            ),
+        )
+    })?;
+    fs::write(path, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::new(2022, 1, 1, Some(24000), Duration::from_micros(30)),
+            PartResult::new(2022, 1, 2, Some("#..\n.#."), Duration::from_micros(10)),
+            PartResult::new(2022, 2, 1, None::<u32>, Duration::from_micros(5)),
+        ]
+    }
+
+    #[test]
+    fn test_markdown() {
+        let results = results();
+        assert_eq!(
+            TimingTable::new(&results).to_markdown(),
+            "| Day | Part | Answer | Time | Share |\n\
+             | --- | --- | --- | ---: | ---: |\n\
+             | 1 | 1 | 24000 | 30.00µs | 75.0% |\n\
+             | 1 | 2 | #..… | 10.00µs | 25.0% |\n\
+             | 2 | 1 | not solved | - | - |\n\
+             | **Total** |  |  | **40.00µs** | **100.0%** |\n"
+        );
+    }
+
+    #[test]
+    fn test_terminal() {
+        let results = results();
+        let table = TimingTable::new(&results).to_string();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "Day   | Part | Answer     |    Time |  Share");
+        assert_eq!(lines[1], "------+------+------------+---------+-------");
+        assert_eq!(lines[4], "2     |    1 | not solved |       - |      -");
+        assert_eq!(lines[6], "Total |      |            | 40.00µs | 100.0%");
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!(
+            "# AoC\n{}\nold\n{}\nrest\n",
+            README_TABLE_START, README_TABLE_END
+        );
+        assert_eq!(
+            replace_table(&readme, "| new |\n").unwrap(),
+            format!(
+                "# AoC\n{}\n\n| new |\n\n{}\nrest\n",
+                README_TABLE_START, README_TABLE_END
+            )
+        );
+        assert_eq!(replace_table("# AoC\n", "| new |\n"), None);
+    }
+}